@@ -0,0 +1,142 @@
+use crate::uri_builders::BuildError;
+use crate::Scheme;
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+
+/// The root URL of a Bitbucket Server instance, e.g. `https://git.corp.example/bitbucket`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseUrl {
+    scheme: Scheme,
+    host: String,
+    port: Option<u16>,
+    context_path: String,
+}
+
+impl BaseUrl {
+    pub fn new(scheme: Scheme, host: &str) -> Self {
+        Self {
+            scheme,
+            host: host.to_owned(),
+            port: None,
+            context_path: String::new(),
+        }
+    }
+
+    /// Parses an absolute `http` or `https` URL without query or fragment.
+    pub fn parse(url: &str) -> Result<Self, BuildError> {
+        let parsed = Url::parse(url)
+            .map_err(|e| BuildError::new(format!("Invalid base URL '{}': {}", url, e)))?;
+
+        let invalid =
+            |reason: &str| BuildError::new(format!("Invalid base URL '{}': {}", url, reason));
+
+        let scheme = match parsed.scheme() {
+            "http" => Scheme::HTTP,
+            "https" => Scheme::HTTPS,
+            _ => return Err(invalid("scheme must be http or https")),
+        };
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| invalid("host is missing"))?;
+        if parsed.query().is_some() {
+            return Err(invalid("query is not allowed"));
+        }
+        if parsed.fragment().is_some() {
+            return Err(invalid("fragment is not allowed"));
+        }
+
+        Ok(Self {
+            scheme,
+            host: host.to_owned(),
+            port: parsed.port(),
+            context_path: parsed.path().trim_matches('/').to_owned(),
+        })
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the path under which Bitbucket is served, e.g. `bitbucket`.
+    pub fn with_context_path(mut self, context_path: &str) -> Self {
+        self.context_path = context_path.trim_matches('/').to_owned();
+        self
+    }
+
+    pub fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    pub fn context_path(&self) -> &str {
+        &self.context_path
+    }
+}
+
+impl FromStr for BaseUrl {
+    type Err = BuildError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for BaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme.as_str(), self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        if !self.context_path.is_empty() {
+            write!(f, "/{}", self.context_path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_port_and_context_path() {
+        let url = BaseUrl::parse("https://git.corp.example:8443/bitbucket/").unwrap();
+        assert_eq!(url.scheme(), &Scheme::HTTPS);
+        assert_eq!(url.host(), "git.corp.example");
+        assert_eq!(url.port(), Some(8443));
+        assert_eq!(url.context_path(), "bitbucket");
+        assert_eq!(url.to_string(), "https://git.corp.example:8443/bitbucket");
+    }
+
+    #[test]
+    fn parse_drops_default_port() {
+        let url: BaseUrl = "http://stash.test.com:80".parse().unwrap();
+        assert_eq!(url, BaseUrl::new(Scheme::HTTP, "stash.test.com"));
+        assert_eq!(url.to_string(), "http://stash.test.com");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_urls() {
+        assert!(BaseUrl::parse("stash.test.com/bitbucket").is_err());
+        assert!(BaseUrl::parse("ftp://stash.test.com").is_err());
+        assert!(BaseUrl::parse("https://stash.test.com/bitbucket?a=b").is_err());
+        assert!(BaseUrl::parse("https://stash.test.com/bitbucket#top").is_err());
+    }
+
+    #[test]
+    fn builder_methods_work() {
+        let url = BaseUrl::new(Scheme::HTTPS, "stash.test.com")
+            .with_port(7990)
+            .with_context_path("/bitbucket/");
+        assert_eq!(url.to_string(), "https://stash.test.com:7990/bitbucket");
+    }
+}
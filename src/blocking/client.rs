@@ -0,0 +1,112 @@
+use crate::{
+    auth::Authorization,
+    client::BitbucketClient,
+    error::Result,
+    resources::PagedStream,
+    traits::{AsyncRestClient, Payload},
+    Scheme,
+};
+use bytes::Bytes;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+/// A blocking client that drives a [`BitbucketClient`] on its own single-threaded runtime.
+///
+/// It must not be used from within an asynchronous context.
+#[derive(Debug, Clone)]
+pub struct BlockingBitbucketClient {
+    client: BitbucketClient,
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingBitbucketClient {
+    pub fn new(host: &str, scheme: Scheme) -> Self {
+        Self::from_client(BitbucketClient::new(host, scheme))
+    }
+
+    pub fn with_auth(host: &str, scheme: Scheme, auth: Authorization) -> Self {
+        Self::from_client(BitbucketClient::with_auth(host, scheme, auth))
+    }
+
+    pub fn from_url(url: &str) -> Result<Self> {
+        Ok(Self::from_client(BitbucketClient::from_url(url)?))
+    }
+
+    pub fn from_client(client: BitbucketClient) -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the runtime of the blocking client");
+
+        Self {
+            client,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    pub fn client(&self) -> &BitbucketClient {
+        &self.client
+    }
+
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    pub fn get(&self, uri: &str) -> Result<Bytes> {
+        self.block_on(async {
+            let bytes = self.client.get(uri).await?.bytes().await?;
+            Ok(bytes)
+        })
+    }
+
+    pub fn get_as<T>(&self, uri: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.block_on(self.client.get_as(uri))
+    }
+
+    pub fn post<T, P>(&self, uri: &str, payload: Option<P>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        P: Payload,
+    {
+        self.block_on(self.client.post(uri, payload))
+    }
+
+    pub fn put<T, P>(&self, uri: &str, payload: Option<P>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        P: Payload,
+    {
+        self.block_on(self.client.put(uri, payload))
+    }
+
+    pub fn delete(&self, uri: &str) -> Result<()> {
+        self.block_on(self.client.delete(uri))
+    }
+}
+
+/// An iterator over the items of a paged endpoint, fetching the pages on demand.
+pub struct PagedIter<'a, T> {
+    client: &'a BlockingBitbucketClient,
+    stream: PagedStream<'a, T>,
+}
+
+impl<'a, T> PagedIter<'a, T> {
+    pub(crate) fn new(client: &'a BlockingBitbucketClient, stream: PagedStream<'a, T>) -> Self {
+        Self { client, stream }
+    }
+}
+
+impl<'a, T> Iterator for PagedIter<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let client = self.client;
+        client.block_on(self.stream.next())
+    }
+}
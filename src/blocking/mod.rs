@@ -0,0 +1,4 @@
+mod client;
+pub mod resources;
+
+pub use client::*;
@@ -0,0 +1,33 @@
+use crate::blocking::BlockingBitbucketClient;
+use crate::client::BitbucketClient;
+use crate::models::get::Branch;
+use crate::resources::{self, BranchFilter, PageOptions};
+
+pub struct BranchResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::BranchResource<'client, BitbucketClient>,
+}
+
+impl<'client> BranchResource<'client> {
+    pub fn new(
+        client: &'client BlockingBitbucketClient,
+        project: &'client str,
+        repository: &'client str,
+    ) -> Self {
+        let resource = resources::BranchResource::new(client.client(), project, repository);
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_all_branches() -> Vec<Branch>);
+    blocking_stream_fn!(stream_all_branches(options: PageOptions) -> Branch);
+    blocking_fn!(get_branches(filter: &BranchFilter) -> Vec<Branch>);
+    blocking_stream_fn!(stream_branches(filter: &BranchFilter, options: PageOptions) -> Branch);
+    blocking_fn!(get_default_branch() -> Branch);
+    blocking_fn!(set_default_branch(branch: &str) -> ());
+    blocking_fn!(create_branch(
+        name: &str,
+        start_point: &str,
+        message: Option<&str>
+    ) -> Branch);
+    blocking_fn!(delete_branch(name: &str, end_point: Option<&str>) -> ());
+}
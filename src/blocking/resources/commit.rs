@@ -0,0 +1,53 @@
+use crate::blocking::{BlockingBitbucketClient, PagedIter};
+use crate::client::BitbucketClient;
+use crate::error::Result;
+use crate::models::get::{Change, Commit, Diff};
+use crate::resources::{self, ChangesOptions, CommitFilter, DiffOptions, PageOptions};
+
+pub struct CommitResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::CommitResource<'client, BitbucketClient>,
+}
+
+impl<'client> CommitResource<'client> {
+    pub fn new(
+        client: &'client BlockingBitbucketClient,
+        project: &'client str,
+        repository: &'client str,
+    ) -> Self {
+        let resource = resources::CommitResource::new(client.client(), project, repository);
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_all_commits() -> Vec<Commit>);
+    blocking_stream_fn!(stream_all_commits(options: PageOptions) -> Commit);
+    blocking_fn!(get_commits(filter: &CommitFilter) -> Vec<Commit>);
+    blocking_stream_fn!(stream_commits(filter: &CommitFilter, options: PageOptions) -> Commit);
+    blocking_fn!(get_commit(commit: &str) -> Commit);
+    blocking_fn!(watch(commit: &str) -> ());
+    blocking_fn!(unwatch(commit: &str) -> ());
+    blocking_fn!(get_commit_changes(
+        commit: &str,
+        since: Option<&str>,
+        changes_options: &ChangesOptions
+    ) -> Vec<Change>);
+
+    pub fn stream_commit_changes<'a>(
+        &'a self,
+        commit: &'a str,
+        since: Option<&str>,
+        changes_options: &ChangesOptions,
+        options: PageOptions,
+    ) -> Result<PagedIter<'a, Change>> {
+        let stream =
+            self.resource
+                .stream_commit_changes(commit, since, changes_options, options)?;
+        Ok(PagedIter::new(self.client, stream))
+    }
+
+    blocking_fn!(get_commit_diff(
+        commit: &str,
+        since: Option<&str>,
+        diff_options: &DiffOptions
+    ) -> Diff);
+}
@@ -0,0 +1,28 @@
+use crate::blocking::BlockingBitbucketClient;
+use crate::client::BitbucketClient;
+use crate::models::get::{PullRequest, PullRequestCount, PullRequestRole};
+use crate::resources::{self, DashboardPullRequestFilter, PageOptions};
+
+pub struct DashboardResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::DashboardResource<'client, BitbucketClient>,
+}
+
+impl<'client> DashboardResource<'client> {
+    pub fn new(client: &'client BlockingBitbucketClient) -> Self {
+        let resource = resources::DashboardResource::new(client.client());
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_pull_requests(filter: &DashboardPullRequestFilter) -> Vec<PullRequest>);
+    blocking_stream_fn!(stream_pull_requests(
+        filter: &DashboardPullRequestFilter,
+        options: PageOptions
+    ) -> PullRequest);
+    blocking_fn!(get_inbox_pull_requests(role: Option<PullRequestRole>) -> Vec<PullRequest>);
+    blocking_stream_fn!(stream_inbox_pull_requests(
+        role: Option<PullRequestRole>,
+        options: PageOptions
+    ) -> PullRequest);
+    blocking_fn!(get_inbox_pull_request_count() -> PullRequestCount);
+}
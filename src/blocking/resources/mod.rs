@@ -0,0 +1,35 @@
+macro_rules! blocking_fn {
+    ($fn_name:ident($($arg:ident: $arg_type:ty),*) -> $ret:ty) => {
+        pub fn $fn_name(&self, $($arg: $arg_type),*) -> crate::error::Result<$ret> {
+            self.client.block_on(self.resource.$fn_name($($arg),*))
+        }
+    };
+}
+
+macro_rules! blocking_stream_fn {
+    ($fn_name:ident($($arg:ident: $arg_type:ty),*) -> $item:ty) => {
+        pub fn $fn_name(
+            &self,
+            $($arg: $arg_type),*
+        ) -> crate::error::Result<crate::blocking::PagedIter<'client, $item>> {
+            let stream = self.resource.$fn_name($($arg),*)?;
+            Ok(crate::blocking::PagedIter::new(self.client, stream))
+        }
+    };
+}
+
+mod branch;
+mod commit;
+mod dashboard;
+mod project;
+mod pull_request;
+mod repository;
+mod tag;
+
+pub use branch::*;
+pub use commit::*;
+pub use dashboard::*;
+pub use project::*;
+pub use pull_request::*;
+pub use repository::*;
+pub use tag::*;
@@ -0,0 +1,25 @@
+use crate::blocking::BlockingBitbucketClient;
+use crate::client::BitbucketClient;
+use crate::models::{get, post};
+use crate::resources::{self, PageOptions};
+use bytes::Bytes;
+
+pub struct ProjectResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::ProjectResource<'client, BitbucketClient>,
+}
+
+impl<'client> ProjectResource<'client> {
+    pub fn new(client: &'client BlockingBitbucketClient) -> Self {
+        let resource = resources::ProjectResource::new(client.client());
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_all_projects() -> Vec<get::Project>);
+    blocking_stream_fn!(stream_all_projects(options: PageOptions) -> get::Project);
+    blocking_fn!(get_project(project: &str) -> get::Project);
+    blocking_fn!(get_project_avatar(project: &str) -> Bytes);
+    blocking_fn!(create_project(project: &post::Project) -> get::Project);
+    blocking_fn!(update_project(project: &str, payload: &post::Project) -> get::Project);
+    blocking_fn!(delete_project(project: &str) -> ());
+}
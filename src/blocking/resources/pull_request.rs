@@ -0,0 +1,105 @@
+use crate::blocking::BlockingBitbucketClient;
+use crate::client::BitbucketClient;
+use crate::models::get::{
+    Change, Comment, Commit, Diff, PullRequest, PullRequestActivity, PullRequestActivityFromType,
+    PullRequestMember, PullRequestMergeStatus, PullRequestParticipantStatus, PullRequestState,
+    Task, TaskCount,
+};
+use crate::models::post;
+use crate::resources::{self, ChangesOptions, DiffOptions, PageOptions, PullRequestFilter};
+
+pub struct PullRequestResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::PullRequestResource<'client, BitbucketClient>,
+}
+
+impl<'client> PullRequestResource<'client> {
+    pub fn new(
+        client: &'client BlockingBitbucketClient,
+        project: &'client str,
+        repository: &'client str,
+    ) -> Self {
+        let resource = resources::PullRequestResource::new(client.client(), project, repository);
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_pull_requests(filter: &PullRequestFilter) -> Vec<PullRequest>);
+    blocking_stream_fn!(stream_pull_requests(
+        filter: &PullRequestFilter,
+        options: PageOptions
+    ) -> PullRequest);
+    blocking_fn!(get_all_pull_requests_with_state(state: PullRequestState) -> Vec<PullRequest>);
+    blocking_fn!(get_all_pull_requests() -> Vec<PullRequest>);
+    blocking_fn!(get_all_open_pull_requests() -> Vec<PullRequest>);
+    blocking_fn!(get_all_merged_pull_requests() -> Vec<PullRequest>);
+    blocking_fn!(get_all_declined_pull_requests() -> Vec<PullRequest>);
+    blocking_stream_fn!(stream_all_pull_requests_with_state(
+        state: PullRequestState,
+        options: PageOptions
+    ) -> PullRequest);
+    blocking_stream_fn!(stream_all_pull_requests(options: PageOptions) -> PullRequest);
+    blocking_stream_fn!(stream_all_open_pull_requests(options: PageOptions) -> PullRequest);
+    blocking_stream_fn!(stream_all_merged_pull_requests(options: PageOptions) -> PullRequest);
+    blocking_stream_fn!(stream_all_declined_pull_requests(options: PageOptions) -> PullRequest);
+    blocking_fn!(get_pull_request(id: u64) -> PullRequest);
+    blocking_fn!(create_pull_request(pull_request: &post::PullRequest) -> PullRequest);
+    blocking_fn!(update_pull_request(
+        id: u64,
+        version: u32,
+        update: &post::PullRequestUpdate
+    ) -> PullRequest);
+    blocking_fn!(can_merge(id: u64) -> PullRequestMergeStatus);
+    blocking_fn!(merge_pull_request(id: u64, version: u32) -> PullRequest);
+    blocking_fn!(decline_pull_request(id: u64, version: u32) -> PullRequest);
+    blocking_fn!(reopen_pull_request(id: u64, version: u32) -> PullRequest);
+    blocking_fn!(approve_pull_request(id: u64) -> PullRequestMember);
+    blocking_fn!(unapprove_pull_request(id: u64) -> ());
+    blocking_fn!(watch(id: u64) -> ());
+    blocking_fn!(unwatch(id: u64) -> ());
+    blocking_fn!(set_reviewer_status(
+        id: u64,
+        user_slug: &str,
+        user_name: &str,
+        status: PullRequestParticipantStatus
+    ) -> PullRequestMember);
+    blocking_fn!(get_all_participants(id: u64) -> Vec<PullRequestMember>);
+    blocking_stream_fn!(stream_all_participants(id: u64, options: PageOptions) -> PullRequestMember);
+    blocking_fn!(add_participant(
+        id: u64,
+        participant: &post::PullRequestParticipant
+    ) -> PullRequestMember);
+    blocking_fn!(add_reviewer(id: u64, user_name: &str) -> PullRequestMember);
+    blocking_fn!(remove_participant(id: u64, user_slug: &str) -> ());
+    blocking_fn!(create_comment(id: u64, comment: &post::Comment) -> Comment);
+    blocking_fn!(reply_to_comment(id: u64, parent_id: u64, text: &str) -> Comment);
+    blocking_fn!(get_comment(id: u64, comment_id: u64) -> Comment);
+    blocking_fn!(update_comment(id: u64, comment_id: u64, version: u32, text: &str) -> Comment);
+    blocking_fn!(delete_comment(id: u64, comment_id: u64, version: u32) -> ());
+    blocking_fn!(get_all_activities(id: u64) -> Vec<PullRequestActivity>);
+    blocking_stream_fn!(stream_all_activities(id: u64, options: PageOptions) -> PullRequestActivity);
+    blocking_stream_fn!(stream_all_activities_from(
+        id: u64,
+        from_id: u64,
+        from_type: PullRequestActivityFromType,
+        options: PageOptions
+    ) -> PullRequestActivity);
+    blocking_fn!(get_all_tasks(id: u64) -> Vec<Task>);
+    blocking_stream_fn!(stream_all_tasks(id: u64, options: PageOptions) -> Task);
+    blocking_fn!(get_task_count(id: u64) -> TaskCount);
+    blocking_fn!(create_task(comment_id: u64, text: &str) -> Task);
+    blocking_fn!(update_task(task_id: u64, update: &post::TaskUpdate) -> Task);
+    blocking_fn!(resolve_task(task_id: u64) -> Task);
+    blocking_fn!(reopen_task(task_id: u64) -> Task);
+    blocking_fn!(get_pull_request_commits(id: u64) -> Vec<Commit>);
+    blocking_stream_fn!(stream_pull_request_commits(id: u64, options: PageOptions) -> Commit);
+    blocking_fn!(get_pull_request_changes(
+        id: u64,
+        changes_options: &ChangesOptions
+    ) -> Vec<Change>);
+    blocking_stream_fn!(stream_pull_request_changes(
+        id: u64,
+        changes_options: &ChangesOptions,
+        options: PageOptions
+    ) -> Change);
+    blocking_fn!(get_pull_request_diff(id: u64, diff_options: &DiffOptions) -> Diff);
+}
@@ -0,0 +1,67 @@
+use crate::blocking::{BlockingBitbucketClient, PagedIter};
+use crate::client::BitbucketClient;
+use crate::error::Result;
+use crate::models::get::{Repository, Tag};
+use crate::models::post;
+use crate::resources::{self, PageOptions};
+
+pub struct RepositoryResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::RepositoryResource<'client, BitbucketClient>,
+}
+
+impl<'client> RepositoryResource<'client> {
+    pub fn new(client: &'client BlockingBitbucketClient, project: &'client str) -> Self {
+        let resource = resources::RepositoryResource::new(client.client(), project);
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_all_repositories() -> Vec<Repository>);
+    blocking_stream_fn!(stream_all_repositories(options: PageOptions) -> Repository);
+    blocking_fn!(get_repository(repository: &str) -> Repository);
+    blocking_fn!(get_all_repository_tags(repository: &str) -> Vec<Tag>);
+
+    pub fn stream_all_repository_tags<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedIter<'a, Tag>> {
+        let stream = self
+            .resource
+            .stream_all_repository_tags(repository, options)?;
+        Ok(PagedIter::new(self.client, stream))
+    }
+
+    blocking_fn!(create_repository(repository: &post::Repository) -> Repository);
+    blocking_fn!(update_repository(repository: &str, payload: &post::Repository) -> Repository);
+    blocking_fn!(delete_repository(repository: &str) -> ());
+    blocking_fn!(fork_repository(
+        repository: &str,
+        project: &str,
+        name: Option<&str>
+    ) -> Repository);
+    blocking_fn!(recreate_repository(repository: &str) -> Repository);
+    blocking_fn!(get_repository_forks(repository: &str) -> Vec<Repository>);
+
+    pub fn stream_repository_forks<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedIter<'a, Repository>> {
+        let stream = self.resource.stream_repository_forks(repository, options)?;
+        Ok(PagedIter::new(self.client, stream))
+    }
+
+    blocking_fn!(get_related_repositories(repository: &str) -> Vec<Repository>);
+
+    pub fn stream_related_repositories<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedIter<'a, Repository>> {
+        let stream = self
+            .resource
+            .stream_related_repositories(repository, options)?;
+        Ok(PagedIter::new(self.client, stream))
+    }
+}
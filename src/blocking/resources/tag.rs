@@ -0,0 +1,27 @@
+use crate::blocking::BlockingBitbucketClient;
+use crate::client::BitbucketClient;
+use crate::models::get::Tag;
+use crate::resources::{self, PageOptions, TagFilter};
+
+pub struct TagResource<'client> {
+    client: &'client BlockingBitbucketClient,
+    resource: resources::TagResource<'client, BitbucketClient>,
+}
+
+impl<'client> TagResource<'client> {
+    pub fn new(
+        client: &'client BlockingBitbucketClient,
+        project: &'client str,
+        repository: &'client str,
+    ) -> Self {
+        let resource = resources::TagResource::new(client.client(), project, repository);
+        Self { client, resource }
+    }
+
+    blocking_fn!(get_tags(filter: &TagFilter) -> Vec<Tag>);
+    blocking_stream_fn!(stream_tags(filter: &TagFilter, options: PageOptions) -> Tag);
+    blocking_fn!(get_tag(name: &str) -> Tag);
+    blocking_fn!(create_tag(name: &str, start_point: &str) -> Tag);
+    blocking_fn!(create_annotated_tag(name: &str, start_point: &str, message: &str) -> Tag);
+    blocking_fn!(delete_tag(name: &str) -> ());
+}
@@ -0,0 +1,237 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    requests_per_second: f64,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Allows `requests_per_second` requests on average, with up to `burst` requests at once.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            requests_per_second: requests_per_second.max(f64::MIN_POSITIVE),
+            burst: burst.max(1),
+        }
+    }
+
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LimiterMetrics {
+    pub requests: u64,
+    pub delayed_requests: u64,
+    pub rate_limit_wait: Duration,
+    pub concurrency_wait: Duration,
+}
+
+impl LimiterMetrics {
+    pub fn total_wait(&self) -> Duration {
+        self.rate_limit_wait + self.concurrency_wait
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Takes a token and returns how long the caller has to wait before it may use it.
+    ///
+    /// The bucket is allowed to go into debt, so that concurrent callers are served in order.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * self.limit.requests_per_second).min(self.limit.burst as f64);
+        self.updated = now;
+        self.tokens -= 1.0;
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.limit.requests_per_second)
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Metrics {
+    requests: AtomicU64,
+    delayed_requests: AtomicU64,
+    rate_limit_wait: AtomicU64,
+    concurrency_wait: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    bucket: Option<Mutex<TokenBucket>>,
+    in_flight: Option<Arc<Semaphore>>,
+    metrics: Metrics,
+}
+
+/// Limits the rate and the concurrency of the requests of a client.
+///
+/// The limits are shared between all the clones of a limiter, and therefore between all
+/// the clones of the client that uses it.
+#[derive(Debug, Clone, Default)]
+pub struct RequestLimiter {
+    state: Arc<LimiterState>,
+}
+
+pub(crate) struct RequestPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl RequestLimiter {
+    pub fn new(rate_limit: Option<RateLimit>, max_in_flight: Option<usize>) -> Self {
+        let state = LimiterState {
+            bucket: rate_limit.map(|limit| Mutex::new(TokenBucket::new(limit))),
+            in_flight: max_in_flight.map(|max| Arc::new(Semaphore::new(max.max(1)))),
+            metrics: Metrics::default(),
+        };
+
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn metrics(&self) -> LimiterMetrics {
+        let metrics = &self.state.metrics;
+
+        LimiterMetrics {
+            requests: metrics.requests.load(Ordering::Relaxed),
+            delayed_requests: metrics.delayed_requests.load(Ordering::Relaxed),
+            rate_limit_wait: Duration::from_nanos(metrics.rate_limit_wait.load(Ordering::Relaxed)),
+            concurrency_wait: Duration::from_nanos(
+                metrics.concurrency_wait.load(Ordering::Relaxed),
+            ),
+        }
+    }
+
+    pub(crate) async fn acquire(&self) -> RequestPermit {
+        let metrics = &self.state.metrics;
+        metrics.requests.fetch_add(1, Ordering::Relaxed);
+
+        let rate_limit_wait = match &self.state.bucket {
+            Some(bucket) => {
+                let wait = bucket.lock().unwrap().reserve(Instant::now());
+                if !wait.is_zero() {
+                    tokio::time::sleep(wait).await;
+                }
+                wait
+            }
+            None => Duration::ZERO,
+        };
+
+        let (permit, concurrency_wait) = match &self.state.in_flight {
+            Some(semaphore) => match semaphore.clone().try_acquire_owned() {
+                Ok(permit) => (Some(permit), Duration::ZERO),
+                Err(_) => {
+                    let started = Instant::now();
+                    let permit = semaphore
+                        .clone()
+                        .acquire_owned()
+                        .await
+                        .expect("the semaphore is never closed");
+                    (Some(permit), started.elapsed())
+                }
+            },
+            None => (None, Duration::ZERO),
+        };
+
+        if !rate_limit_wait.is_zero() || !concurrency_wait.is_zero() {
+            metrics.delayed_requests.fetch_add(1, Ordering::Relaxed);
+        }
+        metrics
+            .rate_limit_wait
+            .fetch_add(as_nanos(rate_limit_wait), Ordering::Relaxed);
+        metrics
+            .concurrency_wait
+            .fetch_add(as_nanos(concurrency_wait), Ordering::Relaxed);
+
+        RequestPermit { _permit: permit }
+    }
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_bursts() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(RateLimit::new(1.0, 3));
+        bucket.updated = now;
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_secs(1));
+        assert_eq!(bucket.reserve(now), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(RateLimit::new(10.0, 1));
+        bucket.updated = now;
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(100));
+
+        let later = now + Duration::from_secs(10);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn clones_share_limits() {
+        let limiter = RequestLimiter::new(None, Some(1));
+        let clone = limiter.clone();
+
+        let permit = limiter.acquire().await;
+        let waiting = tokio::spawn(async move {
+            clone.acquire().await;
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting.is_finished());
+
+        drop(permit);
+        waiting.await.unwrap();
+
+        let metrics = limiter.metrics();
+        assert_eq!(metrics.requests, 2);
+        assert_eq!(metrics.delayed_requests, 1);
+        assert!(metrics.concurrency_wait >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    auth::Authorization,
+    error::{Error, Result},
+    models::get::BitbucketErrors,
+    traits::{AsyncRestClient, Payload},
+    BaseUrl, Scheme,
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use limit::RequestPermit;
+use reqwest::{
+    header::CONTENT_TYPE, Body, Client, RequestBuilder, Response, ResponseBuilderExt, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+
+mod limit;
+mod retry;
+
+pub use limit::{LimiterMetrics, RateLimit, RequestLimiter};
+pub use retry::RetryPolicy;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ApiResult<R> {
+    Ok(R),
+    Err(BitbucketErrors),
+}
+
+impl<R> ApiResult<R> {
+    pub fn to_result(self, status: StatusCode) -> Result<R> {
+        match self {
+            ApiResult::Ok(r) => Ok(r),
+            ApiResult::Err(e) => Err(Error::from_status(status, e)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Builder)]
+pub struct BitbucketClient {
+    #[builder(default)]
+    http_client: Client,
+    base_url: BaseUrl,
+    #[builder(default)]
+    auth: Option<Authorization>,
+    #[builder(default = "RetryPolicy::disabled()")]
+    retry_policy: RetryPolicy,
+    #[builder(default)]
+    limiter: RequestLimiter,
+}
+
+impl Default for BitbucketClient {
+    fn default() -> Self {
+        Self {
+            http_client: Client::new(),
+            base_url: BaseUrl::new(Scheme::HTTP, ""),
+            auth: None,
+            retry_policy: RetryPolicy::disabled(),
+            limiter: RequestLimiter::unlimited(),
+        }
+    }
+}
+
+impl BitbucketClient {
+    pub fn with_auth(host: &str, scheme: Scheme, auth: Authorization) -> Self {
+        Self {
+            base_url: BaseUrl::new(scheme, host),
+            auth: Some(auth),
+            ..Default::default()
+        }
+    }
+}
+
+impl BitbucketClient {
+    pub fn new(host: &str, scheme: Scheme) -> Self {
+        Self::from_base_url(BaseUrl::new(scheme, host))
+    }
+
+    pub fn from_base_url(base_url: BaseUrl) -> Self {
+        Self {
+            base_url,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a client for the instance at the given URL, e.g. `https://git.corp.example/bitbucket`.
+    pub fn from_url(url: &str) -> Result<Self> {
+        Ok(Self::from_base_url(BaseUrl::parse(url)?))
+    }
+
+    pub fn auth(&self) -> &Option<Authorization> {
+        &self.auth
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn limiter(&self) -> &RequestLimiter {
+        &self.limiter
+    }
+
+    #[inline]
+    fn maybe_add_auth(&self, builder: RequestBuilder) -> RequestBuilder {
+        if let Some(ref auth) = self.auth {
+            match auth {
+                Authorization::Basic(username, password) => {
+                    builder.basic_auth(username, Some(password))
+                }
+                Authorization::Bear(bear) => builder.bearer_auth(bear),
+            }
+        } else {
+            builder
+        }
+    }
+
+    #[inline]
+    fn maybe_add_payload<P>(&self, builder: RequestBuilder, payload: Option<&P>) -> RequestBuilder
+    where
+        P: Payload,
+    {
+        if let Some(payload) = payload {
+            builder.json(payload)
+        } else {
+            builder
+        }
+    }
+
+    async fn perform<F>(&self, method: F) -> Result<Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let request = self.maybe_add_auth(method()).build()?;
+            let can_retry = self.retry_policy.can_retry(request.method(), attempt);
+
+            let permit = self.limiter.acquire().await;
+            let result = self
+                .http_client
+                .execute(request)
+                .await
+                .map(|resp| hold_permit(resp, permit));
+
+            let delay = match result {
+                Ok(resp) if can_retry && self.retry_policy.retries_status(resp.status()) => {
+                    let retry_after = retry::retry_after(resp.headers());
+                    self.retry_policy.delay(attempt, retry_after)
+                }
+                Err(e) if can_retry && self.retry_policy.retries_error(&e) => {
+                    self.retry_policy.delay(attempt, None)
+                }
+                Ok(resp) => return error_for_status(resp).await,
+                Err(e) => return Err(e.into()),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn perform_as<T, F>(&self, method: F) -> Result<T>
+    where
+        F: Fn() -> RequestBuilder,
+        T: DeserializeOwned,
+    {
+        let resp = self.perform(method).await?;
+        let status = resp.status();
+        let content_type = content_type(&resp);
+        let body = resp.text().await?;
+
+        // Endpoints without a response body can only be deserialized into types
+        // that accept `null`, such as `()` or `Option<T>`.
+        if status == StatusCode::NO_CONTENT || body.trim().is_empty() {
+            return serde_json::from_str("null").map_err(|e| Error::deserialize(e, &body));
+        }
+
+        match content_type {
+            Some(content_type) if !is_json(&content_type) => {
+                Err(Error::content_type(status, content_type, &body))
+            }
+            _ => serde_json::from_str(&body).map_err(|e| Error::deserialize(e, &body)),
+        }
+    }
+}
+
+/// Ties the permit to the body of the response, so that `max_in_flight` also limits the
+/// downloads of the response bodies. The permit is released once the body has been read
+/// or the response is dropped.
+fn hold_permit(resp: Response, permit: RequestPermit) -> Response {
+    let mut builder = http::Response::builder()
+        .status(resp.status())
+        .version(resp.version())
+        .url(resp.url().clone());
+
+    if let Some(headers) = builder.headers_mut() {
+        *headers = resp.headers().clone();
+    }
+
+    let body = resp.bytes_stream().map(move |chunk| {
+        let _permit = &permit;
+        chunk
+    });
+
+    builder
+        .body(Body::wrap_stream(body))
+        .expect("the parts of a valid response are valid")
+        .into()
+}
+
+fn content_type(resp: &Response) -> Option<String> {
+    resp.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned())
+}
+
+fn is_json(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+async fn error_for_status(resp: Response) -> Result<Response> {
+    let status = resp.status();
+    if !(status.is_client_error() || status.is_server_error()) {
+        return Ok(resp);
+    }
+
+    let is_json_body = match content_type(&resp) {
+        Some(content_type) => is_json(&content_type),
+        None => true,
+    };
+    let body = resp.text().await?;
+    let errors = if is_json_body {
+        serde_json::from_str::<BitbucketErrors>(&body).unwrap_or_default()
+    } else {
+        BitbucketErrors::default()
+    };
+
+    Err(Error::from_status(status, errors))
+}
+
+#[async_trait]
+impl AsyncRestClient for BitbucketClient {
+    fn base_url(&self) -> &BaseUrl {
+        &self.base_url
+    }
+
+    async fn get(&self, uri: &str) -> Result<Response> {
+        self.perform(|| self.http_client.get(uri)).await
+    }
+
+    async fn get_as<T>(&self, uri: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.perform_as(|| self.http_client.get(uri)).await
+    }
+
+    async fn post<T, P>(&self, uri: &str, payload: Option<P>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        P: Payload,
+    {
+        self.perform_as(|| {
+            let builder = self.http_client.post(uri);
+            self.maybe_add_payload(builder, payload.as_ref())
+        })
+        .await
+    }
+
+    async fn put<T, P>(&self, uri: &str, payload: Option<P>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        P: Payload,
+    {
+        self.perform_as(|| {
+            let builder = self.http_client.put(uri);
+            self.maybe_add_payload(builder, payload.as_ref())
+        })
+        .await
+    }
+
+    async fn delete(&self, uri: &str) -> Result<()> {
+        self.perform(|| self.http_client.delete(uri)).await?;
+        Ok(())
+    }
+
+    async fn delete_with<P>(&self, uri: &str, payload: Option<P>) -> Result<()>
+    where
+        P: Payload,
+    {
+        self.perform(|| {
+            let builder = self.http_client.delete(uri);
+            self.maybe_add_payload(builder, payload.as_ref())
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Method, StatusCode,
+};
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    statuses: Vec<StatusCode>,
+    retry_timeouts: bool,
+    retry_connect_errors: bool,
+    respect_retry_after: bool,
+    idempotent_only: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_timeouts: true,
+            retry_connect_errors: true,
+            respect_retry_after: true,
+            idempotent_only: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once.
+    pub fn disabled() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// The total number of attempts per request, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    pub fn retry_timeouts(mut self, retry_timeouts: bool) -> Self {
+        self.retry_timeouts = retry_timeouts;
+        self
+    }
+
+    pub fn retry_connect_errors(mut self, retry_connect_errors: bool) -> Self {
+        self.retry_connect_errors = retry_connect_errors;
+        self
+    }
+
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Whether only idempotent requests (GET, HEAD, PUT, DELETE, OPTIONS, TRACE) are retried.
+    pub fn idempotent_only(mut self, idempotent_only: bool) -> Self {
+        self.idempotent_only = idempotent_only;
+        self
+    }
+
+    pub(crate) fn can_retry(&self, method: &Method, attempt: u32) -> bool {
+        attempt < self.max_attempts && (!self.idempotent_only || is_idempotent(method))
+    }
+
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    pub(crate) fn retries_error(&self, error: &reqwest::Error) -> bool {
+        (self.retry_timeouts && error.is_timeout())
+            || (self.retry_connect_errors && error.is_connect())
+    }
+
+    /// The time to wait before the attempt following `attempt`.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let (true, Some(retry_after)) = (self.respect_retry_after, retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        if self.jitter {
+            // Equal jitter: keep half of the delay and randomize the rest.
+            let half = delay / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            delay
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS | Method::TRACE
+    )
+}
+
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .jitter(false)
+    }
+
+    #[test]
+    fn delay_grows_exponentially_up_to_max_delay() {
+        let policy = policy();
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(3, None), Duration::from_millis(400));
+        assert_eq!(policy.delay(5, None), Duration::from_secs(1));
+        assert_eq!(policy.delay(100, None), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn jitter_stays_within_delay() {
+        let policy = policy().jitter(true);
+        for _ in 0..100 {
+            let delay = policy.delay(2, None);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = policy();
+        let delay = policy.delay(1, Some(Duration::from_millis(700)));
+        assert_eq!(delay, Duration::from_millis(700));
+
+        let delay = policy.delay(1, Some(Duration::from_secs(30)));
+        assert_eq!(delay, Duration::from_secs(1));
+
+        let policy = policy.respect_retry_after(false);
+        let delay = policy.delay(1, Some(Duration::from_millis(700)));
+        assert_eq!(delay, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn only_idempotent_methods_are_retried_by_default() {
+        let policy = policy();
+        assert!(policy.can_retry(&Method::GET, 1));
+        assert!(policy.can_retry(&Method::PUT, 1));
+        assert!(policy.can_retry(&Method::DELETE, 1));
+        assert!(!policy.can_retry(&Method::POST, 1));
+        assert!(policy.idempotent_only(false).can_retry(&Method::POST, 1));
+    }
+
+    #[test]
+    fn attempts_are_limited() {
+        let policy = policy().max_attempts(2);
+        assert!(policy.can_retry(&Method::GET, 1));
+        assert!(!policy.can_retry(&Method::GET, 2));
+        assert!(!RetryPolicy::disabled().can_retry(&Method::GET, 1));
+    }
+
+    #[test]
+    fn retry_after_parses_seconds_and_dates() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{models::get::BitbucketErrors, uri_builders::BuildError};
+use reqwest::StatusCode;
+use std::fmt;
+
+const BODY_EXCERPT_LEN: usize = 512;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The server answered with an error status code.
+    Http {
+        status: StatusCode,
+        errors: BitbucketErrors,
+    },
+    /// The server rejected the credentials (401) or the user lacks the permissions (403).
+    Auth {
+        status: StatusCode,
+        errors: BitbucketErrors,
+    },
+    /// The server rejected a change because it was made against an outdated version of the
+    /// entity, e.g. merging a pull request that has been updated in the meantime (409).
+    StaleVersion {
+        current_version: u32,
+        expected_version: Option<u32>,
+        errors: BitbucketErrors,
+    },
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest::Error),
+    /// The response body could not be deserialized into the expected model.
+    Deserialize {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The server answered with a body that is not JSON, e.g. an HTML error page of a proxy.
+    ContentType {
+        status: StatusCode,
+        content_type: String,
+        body: String,
+    },
+    /// The URI of the resource could not be built.
+    UriBuild(BuildError),
+}
+
+impl Error {
+    pub fn from_status(status: StatusCode, errors: BitbucketErrors) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Error::Auth { status, errors },
+            StatusCode::CONFLICT => match errors.errors.iter().find_map(|e| e.current_version) {
+                Some(current_version) => Error::StaleVersion {
+                    current_version,
+                    expected_version: errors.errors.iter().find_map(|e| e.expected_version),
+                    errors,
+                },
+                None => Error::Http { status, errors },
+            },
+            _ => Error::Http { status, errors },
+        }
+    }
+
+    pub fn deserialize(source: serde_json::Error, body: &str) -> Self {
+        let body = excerpt(body);
+        Error::Deserialize { source, body }
+    }
+
+    pub fn content_type(status: StatusCode, content_type: String, body: &str) -> Self {
+        let body = excerpt(body);
+        Error::ContentType {
+            status,
+            content_type,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Http { status, .. }
+            | Error::Auth { status, .. }
+            | Error::ContentType { status, .. } => Some(*status),
+            Error::StaleVersion { .. } => Some(StatusCode::CONFLICT),
+            Error::Transport(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    pub fn errors(&self) -> Option<&BitbucketErrors> {
+        match self {
+            Error::Http { errors, .. }
+            | Error::Auth { errors, .. }
+            | Error::StaleVersion { errors, .. } => Some(errors),
+            _ => None,
+        }
+    }
+
+    /// The current version of the entity if the request failed because of a stale version.
+    pub fn current_version(&self) -> Option<u32> {
+        match self {
+            Error::StaleVersion {
+                current_version, ..
+            } => Some(*current_version),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(StatusCode::CONFLICT)
+    }
+
+    pub fn is_stale_version(&self) -> bool {
+        matches!(self, Error::StaleVersion { .. })
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(StatusCode::UNAUTHORIZED)
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        self.status() == Some(StatusCode::FORBIDDEN)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        match self {
+            Error::Http { status, errors } => write!(f, "HTTP {}: {}", status, errors),
+            Error::Auth { status, errors } => {
+                write!(f, "Authorization failed with HTTP {}: {}", status, errors)
+            }
+            Error::StaleVersion {
+                current_version,
+                errors,
+                ..
+            } => write!(
+                f,
+                "Stale version, the current version is {}: {}",
+                current_version, errors
+            ),
+            Error::Transport(e) => write!(f, "Transport error: {}", e),
+            Error::Deserialize { source, body } => {
+                write!(f, "Failed to deserialize response: {}\n{}", source, body)
+            }
+            Error::ContentType {
+                status,
+                content_type,
+                body,
+            } => write!(
+                f,
+                "Expected a JSON response but got HTTP {} with content type {}:\n{}",
+                status, content_type, body
+            ),
+            Error::UriBuild(e) => write!(f, "Failed to build URI: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http { errors, .. }
+            | Error::Auth { errors, .. }
+            | Error::StaleVersion { errors, .. } => Some(errors),
+            Error::Transport(e) => Some(e),
+            Error::Deserialize { source, .. } => Some(source),
+            Error::ContentType { .. } => None,
+            Error::UriBuild(e) => Some(e),
+        }
+    }
+}
+
+fn excerpt(body: &str) -> String {
+    match body.char_indices().nth(BODY_EXCERPT_LEN) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_owned(),
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Transport(e)
+    }
+}
+
+impl From<BuildError> for Error {
+    fn from(e: BuildError) -> Self {
+        Error::UriBuild(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::get::BitbucketError;
+
+    fn errors() -> BitbucketErrors {
+        BitbucketErrors {
+            errors: vec![BitbucketError {
+                context: None,
+                message: "Something went wrong".to_owned(),
+                exception_name: None,
+                current_version: None,
+                expected_version: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn from_status_maps_auth_failures() {
+        let e = Error::from_status(StatusCode::UNAUTHORIZED, errors());
+        assert!(matches!(e, Error::Auth { .. }));
+        assert!(e.is_unauthorized());
+
+        let e = Error::from_status(StatusCode::FORBIDDEN, errors());
+        assert!(matches!(e, Error::Auth { .. }));
+        assert!(e.is_forbidden());
+    }
+
+    #[test]
+    fn from_status_keeps_status_and_errors() {
+        let e = Error::from_status(StatusCode::CONFLICT, errors());
+        assert!(e.is_conflict());
+        assert!(!e.is_not_found());
+        assert_eq!(e.status(), Some(StatusCode::CONFLICT));
+        assert_eq!(e.errors(), Some(&errors()));
+    }
+
+    #[test]
+    fn from_status_detects_stale_versions() {
+        let mut errors = errors();
+        errors.errors[0].current_version = Some(3);
+        errors.errors[0].expected_version = Some(2);
+
+        let e = Error::from_status(StatusCode::CONFLICT, errors);
+        assert!(e.is_conflict());
+        assert!(e.is_stale_version());
+        assert_eq!(e.current_version(), Some(3));
+        assert!(matches!(
+            e,
+            Error::StaleVersion {
+                expected_version: Some(2),
+                ..
+            }
+        ));
+
+        let e = Error::from_status(StatusCode::CONFLICT, self::errors());
+        assert!(!e.is_stale_version());
+        assert_eq!(e.current_version(), None);
+    }
+
+    #[test]
+    fn deserialize_truncates_body() {
+        let body = "x".repeat(BODY_EXCERPT_LEN * 2);
+        let source = serde_json::from_str::<u32>(&body).unwrap_err();
+
+        match Error::deserialize(source, &body) {
+            Error::Deserialize { body, .. } => {
+                assert_eq!(body.len(), BODY_EXCERPT_LEN + 3);
+                assert!(body.ends_with("..."));
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+}
@@ -3,14 +3,30 @@ extern crate derive_builder;
 extern crate async_trait;
 
 pub mod auth;
+pub mod base_url;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod error;
 pub mod models;
 pub mod resources;
 pub mod traits;
 pub mod uri_builders;
 
-#[derive(Debug, Clone)]
+pub use base_url::BaseUrl;
+pub use error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Scheme {
     HTTP,
     HTTPS,
 }
+
+impl Scheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::HTTP => "http",
+            Scheme::HTTPS => "https",
+        }
+    }
+}
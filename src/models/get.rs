@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer, Serialize};
 use std::fmt;
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
@@ -7,9 +7,13 @@ pub struct BitbucketError {
     pub message: String,
     #[serde(rename(deserialize = "exceptionName"))]
     pub exception_name: Option<String>,
+    #[serde(rename(deserialize = "currentVersion"))]
+    pub current_version: Option<u32>,
+    #[serde(rename(deserialize = "expectedVersion"))]
+    pub expected_version: Option<u32>,
 }
 
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
 pub struct BitbucketErrors {
     pub errors: Vec<BitbucketError>,
 }
@@ -127,6 +131,29 @@ pub struct Branch {
     pub latest_commit: String,
     #[serde(rename(deserialize = "isDefault"))]
     pub is_default: bool,
+    /// Only returned when the branches are listed with details.
+    pub metadata: Option<BranchMetadata>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct AheadBehind {
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct BranchMetadata {
+    /// The number of commits the branch is ahead and behind of the base branch.
+    #[serde(
+        rename(
+            deserialize = "com.atlassian.bitbucket.server.bitbucket-branch:ahead-behind-metadata-provider"
+        ),
+        default
+    )]
+    pub ahead_behind: Option<AheadBehind>,
+    /// The metadata of the other providers, keyed by provider.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
@@ -151,8 +178,14 @@ pub struct Commit {
     pub author: Author,
     #[serde(rename(deserialize = "authorTimestamp"))]
     pub author_timestamp: u64,
+    pub committer: Option<Author>,
+    #[serde(rename(deserialize = "committerTimestamp"))]
+    pub committer_timestamp: Option<u64>,
     pub message: String,
     pub parents: Vec<ParentCommit>,
+    /// Properties added by plugins, e.g. the JIRA keys mentioned in the message.
+    #[serde(default)]
+    pub properties: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
@@ -189,8 +222,11 @@ pub struct PullRequestRef {
 #[derive(Debug, Deserialize, Eq, PartialEq)]
 pub struct PullRequestMember {
     pub user: User,
-    pub role: String,
+    pub role: PullRequestRole,
     pub approved: bool,
+    pub status: PullRequestParticipantStatus,
+    #[serde(rename(deserialize = "lastReviewedCommit"))]
+    pub last_reviewed_commit: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
@@ -199,8 +235,7 @@ pub struct PullRequest {
     pub version: u32,
     pub title: String,
     pub description: Option<String>,
-    // TODO: Make this an enum an deserialize manually
-    pub state: Option<String>,
+    pub state: Option<PullRequestState>,
     pub open: Option<bool>,
     pub closed: Option<bool>,
     #[serde(rename(deserialize = "createdDate"))]
@@ -219,6 +254,307 @@ pub struct PullRequest {
     pub links: Links,
 }
 
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PullRequestMergeVeto {
+    #[serde(rename(deserialize = "summaryMessage"))]
+    pub summary_message: String,
+    #[serde(rename(deserialize = "detailedMessage"))]
+    pub detailed_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PullRequestMergeStatus {
+    #[serde(rename(deserialize = "canMerge"))]
+    pub can_merge: bool,
+    pub conflicted: bool,
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub vetoes: Vec<PullRequestMergeVeto>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct CommentAnchor {
+    pub path: String,
+    #[serde(rename(deserialize = "srcPath"))]
+    pub src_path: Option<String>,
+    pub line: Option<u32>,
+    #[serde(rename(deserialize = "lineType"))]
+    pub line_type: Option<CommentLineType>,
+    #[serde(rename(deserialize = "fileType"))]
+    pub file_type: Option<CommentFileType>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct Comment {
+    pub id: u64,
+    pub version: u32,
+    pub text: String,
+    pub author: User,
+    #[serde(rename(deserialize = "createdDate"))]
+    pub date_created: u64,
+    #[serde(rename(deserialize = "updatedDate"))]
+    pub date_updated: u64,
+    /// The replies to this comment.
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    #[serde(default)]
+    pub properties: serde_json::Map<String, serde_json::Value>,
+    pub anchor: Option<CommentAnchor>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct RescopedCommits {
+    pub commits: Vec<Commit>,
+    pub total: u32,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum PullRequestActivityAction {
+    Commented {
+        comment_action: Option<String>,
+        comment: Box<Comment>,
+        anchor: Option<CommentAnchor>,
+    },
+    Approved,
+    Unapproved,
+    Reviewed,
+    Rescoped {
+        from_hash: Option<String>,
+        previous_from_hash: Option<String>,
+        to_hash: Option<String>,
+        previous_to_hash: Option<String>,
+        added: Option<RescopedCommits>,
+        removed: Option<RescopedCommits>,
+    },
+    Merged {
+        commit: Option<Commit>,
+    },
+    Declined,
+    Reopened,
+    Opened,
+    /// An action this crate does not know about, with the complete JSON of the activity.
+    Unknown(serde_json::Value),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct PullRequestActivity {
+    pub id: u64,
+    pub date_created: u64,
+    pub user: User,
+    pub action: PullRequestActivityAction,
+}
+
+impl<'de> Deserialize<'de> for PullRequestActivity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Activity {
+            id: u64,
+            #[serde(rename = "createdDate")]
+            date_created: u64,
+            user: User,
+            action: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Commented {
+            #[serde(rename = "commentAction")]
+            comment_action: Option<String>,
+            comment: Comment,
+            #[serde(rename = "commentAnchor")]
+            anchor: Option<CommentAnchor>,
+        }
+
+        #[derive(Deserialize)]
+        struct Rescoped {
+            #[serde(rename = "fromHash")]
+            from_hash: Option<String>,
+            #[serde(rename = "previousFromHash")]
+            previous_from_hash: Option<String>,
+            #[serde(rename = "toHash")]
+            to_hash: Option<String>,
+            #[serde(rename = "previousToHash")]
+            previous_to_hash: Option<String>,
+            added: Option<RescopedCommits>,
+            removed: Option<RescopedCommits>,
+        }
+
+        #[derive(Deserialize)]
+        struct Merged {
+            commit: Option<Commit>,
+        }
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let activity = Activity::deserialize(&value).map_err(de::Error::custom)?;
+
+        let action = match activity.action.as_str() {
+            "COMMENTED" => {
+                let commented = Commented::deserialize(&value).map_err(de::Error::custom)?;
+                PullRequestActivityAction::Commented {
+                    comment_action: commented.comment_action,
+                    comment: Box::new(commented.comment),
+                    anchor: commented.anchor,
+                }
+            }
+            "APPROVED" => PullRequestActivityAction::Approved,
+            "UNAPPROVED" => PullRequestActivityAction::Unapproved,
+            "REVIEWED" => PullRequestActivityAction::Reviewed,
+            "RESCOPED" => {
+                let rescoped = Rescoped::deserialize(&value).map_err(de::Error::custom)?;
+                PullRequestActivityAction::Rescoped {
+                    from_hash: rescoped.from_hash,
+                    previous_from_hash: rescoped.previous_from_hash,
+                    to_hash: rescoped.to_hash,
+                    previous_to_hash: rescoped.previous_to_hash,
+                    added: rescoped.added,
+                    removed: rescoped.removed,
+                }
+            }
+            "MERGED" => {
+                let merged = Merged::deserialize(&value).map_err(de::Error::custom)?;
+                PullRequestActivityAction::Merged {
+                    commit: merged.commit,
+                }
+            }
+            "DECLINED" => PullRequestActivityAction::Declined,
+            "REOPENED" => PullRequestActivityAction::Reopened,
+            "OPENED" => PullRequestActivityAction::Opened,
+            _ => PullRequestActivityAction::Unknown(value),
+        };
+
+        Ok(Self {
+            id: activity.id,
+            date_created: activity.date_created,
+            user: activity.user,
+            action,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TaskAnchor {
+    pub id: u64,
+    pub r#type: String,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct Task {
+    pub id: u64,
+    #[serde(rename(deserialize = "createdDate"))]
+    pub date_created: u64,
+    pub author: User,
+    pub text: String,
+    pub state: TaskState,
+    pub anchor: Option<TaskAnchor>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PullRequestCount {
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TaskCount {
+    pub open: u32,
+    pub resolved: u32,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct ChangePath {
+    pub components: Vec<String>,
+    pub parent: String,
+    pub name: String,
+    pub extension: Option<String>,
+    #[serde(rename(deserialize = "toString"))]
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct Change {
+    #[serde(rename(deserialize = "contentId"))]
+    pub content_id: Option<String>,
+    #[serde(rename(deserialize = "fromContentId"))]
+    pub from_content_id: Option<String>,
+    pub path: ChangePath,
+    /// The previous path of moved and copied files.
+    #[serde(rename(deserialize = "srcPath"))]
+    pub src_path: Option<ChangePath>,
+    pub r#type: ChangeType,
+    #[serde(rename(deserialize = "nodeType"))]
+    pub node_type: Option<String>,
+    pub executable: Option<bool>,
+    #[serde(rename(deserialize = "percentUnchanged"))]
+    pub percent_unchanged: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct DiffLine {
+    pub source: u32,
+    pub destination: u32,
+    pub line: String,
+    #[serde(default)]
+    pub truncated: bool,
+    #[serde(rename(deserialize = "commentIds"), default)]
+    pub comment_ids: Vec<u64>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct DiffSegment {
+    pub r#type: DiffSegmentType,
+    pub lines: Vec<DiffLine>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct DiffHunk {
+    pub context: Option<String>,
+    #[serde(rename(deserialize = "sourceLine"))]
+    pub source_line: u32,
+    #[serde(rename(deserialize = "sourceSpan"))]
+    pub source_span: u32,
+    #[serde(rename(deserialize = "destinationLine"))]
+    pub destination_line: u32,
+    #[serde(rename(deserialize = "destinationSpan"))]
+    pub destination_span: u32,
+    pub segments: Vec<DiffSegment>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct FileDiff {
+    /// The path before the change, missing for added files.
+    pub source: Option<ChangePath>,
+    /// The path after the change, missing for deleted files.
+    pub destination: Option<ChangePath>,
+    #[serde(default)]
+    pub hunks: Vec<DiffHunk>,
+    #[serde(default)]
+    pub binary: bool,
+    #[serde(default)]
+    pub truncated: bool,
+    #[serde(rename(deserialize = "lineComments"), default)]
+    pub line_comments: Vec<Comment>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct Diff {
+    #[serde(rename(deserialize = "fromHash"))]
+    pub from_hash: Option<String>,
+    #[serde(rename(deserialize = "toHash"))]
+    pub to_hash: Option<String>,
+    #[serde(rename(deserialize = "contextLines"))]
+    pub context_lines: Option<u32>,
+    pub whitespace: Option<String>,
+    #[serde(default)]
+    pub diffs: Vec<FileDiff>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
 #[derive(Debug, Deserialize, Eq, PartialEq)]
 pub struct Tag {
     pub id: String,
@@ -242,20 +578,166 @@ pub struct ApplicationProperties {
     pub name: String,
 }
 
-pub enum PullRequestState {
-    ALL,
-    OPEN,
-    MERGED,
-    DECLINED,
+string_enum! {
+    pub enum PullRequestState {
+        All => "ALL",
+        Open => "OPEN",
+        Merged => "MERGED",
+        Declined => "DECLINED",
+    }
 }
 
+/// The original names of the states, kept for code written against them.
 impl PullRequestState {
+    #[deprecated(note = "use `PullRequestState::All` instead")]
+    pub const ALL: PullRequestState = PullRequestState::All;
+    #[deprecated(note = "use `PullRequestState::Open` instead")]
+    pub const OPEN: PullRequestState = PullRequestState::Open;
+    #[deprecated(note = "use `PullRequestState::Merged` instead")]
+    pub const MERGED: PullRequestState = PullRequestState::Merged;
+    #[deprecated(note = "use `PullRequestState::Declined` instead")]
+    pub const DECLINED: PullRequestState = PullRequestState::Declined;
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PullRequestDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl PullRequestDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullRequestDirection::Incoming => "INCOMING",
+            PullRequestDirection::Outgoing => "OUTGOING",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PullRequestOrder {
+    Oldest,
+    Newest,
+}
+
+impl PullRequestOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullRequestOrder::Oldest => "OLDEST",
+            PullRequestOrder::Newest => "NEWEST",
+        }
+    }
+}
+
+/// Whether merge commits are listed along with the other commits, on their own or not at all.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CommitMerges {
+    Include,
+    Exclude,
+    Only,
+}
+
+impl CommitMerges {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitMerges::Include => "include",
+            CommitMerges::Exclude => "exclude",
+            CommitMerges::Only => "only",
+        }
+    }
+}
+
+/// The order of listed branches.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BranchOrder {
+    Alphabetical,
+    Modification,
+}
+
+impl BranchOrder {
     pub fn as_str(&self) -> &'static str {
         match self {
-            PullRequestState::ALL => "ALL",
-            PullRequestState::OPEN => "OPEN",
-            PullRequestState::MERGED => "MERGED",
-            PullRequestState::DECLINED => "DECLINED",
+            BranchOrder::Alphabetical => "ALPHABETICAL",
+            BranchOrder::Modification => "MODIFICATION",
         }
     }
 }
+
+string_enum! {
+    /// The order of listed tags.
+    pub enum TagOrder {
+        Alphabetical => "ALPHABETICAL",
+        Modification => "MODIFICATION",
+    }
+}
+
+string_enum! {
+    pub enum PullRequestRole {
+        Author => "AUTHOR",
+        Reviewer => "REVIEWER",
+        Participant => "PARTICIPANT",
+    }
+}
+
+string_enum! {
+    pub enum PullRequestParticipantStatus {
+        Approved => "APPROVED",
+        NeedsWork => "NEEDS_WORK",
+        Unapproved => "UNAPPROVED",
+    }
+}
+
+string_enum! {
+    pub enum CommentLineType {
+        Added => "ADDED",
+        Removed => "REMOVED",
+        Context => "CONTEXT",
+    }
+}
+
+string_enum! {
+    pub enum CommentFileType {
+        From => "FROM",
+        To => "TO",
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PullRequestActivityFromType {
+    Comment,
+    Activity,
+}
+
+impl PullRequestActivityFromType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullRequestActivityFromType::Comment => "COMMENT",
+            PullRequestActivityFromType::Activity => "ACTIVITY",
+        }
+    }
+}
+
+string_enum! {
+    pub enum TaskState {
+        Open => "OPEN",
+        Resolved => "RESOLVED",
+    }
+}
+
+string_enum! {
+    pub enum ChangeType {
+        Add => "ADD",
+        Modify => "MODIFY",
+        Delete => "DELETE",
+        Move => "MOVE",
+        Copy => "COPY",
+    }
+}
+
+string_enum! {
+    pub enum DiffSegmentType {
+        Added => "ADDED",
+        Removed => "REMOVED",
+        Context => "CONTEXT",
+    }
+}
@@ -1,3 +1,6 @@
+use crate::models::get::{
+    CommentFileType, CommentLineType, PullRequestParticipantStatus, PullRequestRole, TaskState,
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
@@ -8,11 +11,81 @@ pub struct Project {
     pub avatar: Option<String>,
 }
 
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct Branch {
+    pub name: String,
+    #[serde(rename(serialize = "startPoint"))]
+    pub start_point: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct BranchDeletion {
+    pub name: String,
+    /// The commit the branch is expected to point to, the deletion fails otherwise.
+    #[serde(
+        rename(serialize = "endPoint"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_point: Option<String>,
+    #[serde(rename(serialize = "dryRun"))]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DefaultBranch {
+    pub id: String,
+}
+
+/// A tag to create, annotated when it has a message and lightweight otherwise.
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct Tag {
+    pub name: String,
+    #[serde(rename(serialize = "startPoint"))]
+    pub start_point: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct RepositoryProject {
+    pub key: String,
+}
+
+/// The payload to create, update or fork a repository, only the fields which are set are sent.
+#[derive(Debug, Default, Serialize, Eq, PartialEq)]
+pub struct Repository {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename(serialize = "scmId"), skip_serializing_if = "Option::is_none")]
+    pub scm_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forkable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    /// The project the repository is moved or forked into.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<RepositoryProject>,
+}
+
 #[derive(Debug, Serialize, Eq, PartialEq)]
 pub struct PullRequestMember {
     pub user: User,
 }
 
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PullRequestParticipant {
+    pub user: User,
+    pub role: PullRequestRole,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PullRequestParticipantStatusUpdate {
+    pub user: User,
+    pub status: PullRequestParticipantStatus,
+}
+
 #[derive(Debug, Serialize, Eq, PartialEq)]
 pub struct User {
     pub name: String,
@@ -47,3 +120,75 @@ pub struct PullRequest {
     pub close_source_branch: bool,
     pub reviewers: Vec<PullRequestMember>,
 }
+
+/// The changes to a pull request, only the fields which are set are sent.
+#[derive(Debug, Default, Serialize, Eq, PartialEq)]
+pub struct PullRequestUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<PullRequestMember>>,
+    #[serde(rename(serialize = "toRef"), skip_serializing_if = "Option::is_none")]
+    pub to_ref: Option<PullRequestRef>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct CommentParent {
+    pub id: u64,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct CommentAnchor {
+    pub path: String,
+    #[serde(rename(serialize = "srcPath"), skip_serializing_if = "Option::is_none")]
+    pub src_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    #[serde(
+        rename(serialize = "lineType"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line_type: Option<CommentLineType>,
+    #[serde(
+        rename(serialize = "fileType"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub file_type: Option<CommentFileType>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct Comment {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CommentParent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<CommentAnchor>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct CommentUpdate {
+    pub version: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct TaskAnchor {
+    pub id: u64,
+    pub r#type: String,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct Task {
+    pub anchor: TaskAnchor,
+    pub text: String,
+}
+
+#[derive(Debug, Default, Serialize, Eq, PartialEq)]
+pub struct TaskUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<TaskState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
@@ -1,10 +1,72 @@
-use crate::uri_builders::{BranchUriBuilder, ResourceUriBuilder, UriBuilder};
-use crate::{models::get::Branch, resources::util::*, traits::AsyncRestClient};
-use anyhow::Result;
+use crate::error::Result;
+use crate::models::{
+    get::{Branch, BranchOrder},
+    post,
+};
+use crate::uri_builders::{
+    ApiRepositoryUriBuilder, BranchUriBuilder, BranchUtilsUriBuilder, QueryUriBuilder,
+    ResourceUriBuilder, TerminalUriBuilder, UriBuilder,
+};
+use crate::{resources::util::*, traits::AsyncRestClient};
+use futures::TryStreamExt;
+
+/// Filters for listing the branches of a repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchFilter {
+    filter_text: Option<String>,
+    order_by: Option<BranchOrder>,
+    base: Option<String>,
+    details: Option<bool>,
+}
+
+impl BranchFilter {
+    pub fn filter_text(mut self, filter_text: &str) -> Self {
+        self.filter_text = Some(filter_text.to_owned());
+        self
+    }
+
+    pub fn order_by(mut self, order_by: BranchOrder) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Lists the branches relative to this branch or commit, e.g. for the ahead and behind counts.
+    pub fn base(mut self, base: &str) -> Self {
+        self.base = Some(base.to_owned());
+        self
+    }
+
+    /// Includes the [`BranchMetadata`](crate::models::get::BranchMetadata) of each branch.
+    pub fn details(mut self, details: bool) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    fn apply<'r>(
+        &self,
+        mut builder: QueryUriBuilder<BranchUriBuilder<'r>>,
+    ) -> QueryUriBuilder<BranchUriBuilder<'r>> {
+        if let Some(filter_text) = &self.filter_text {
+            builder = builder.filter_text(filter_text);
+        }
+        if let Some(order_by) = self.order_by {
+            builder = builder.order_by(order_by);
+        }
+        if let Some(base) = &self.base {
+            builder = builder.base(base);
+        }
+        if let Some(details) = self.details {
+            builder = builder.details(details);
+        }
+        builder
+    }
+}
 
 pub struct BranchResource<'client, C> {
     client: &'client C,
     uri_builder: BranchUriBuilder<'client>,
+    branch_utils_uri_builder:
+        TerminalUriBuilder<ApiRepositoryUriBuilder<'client, BranchUtilsUriBuilder<'client>>>,
 }
 
 impl<'client, C> BranchResource<'client, C>
@@ -12,32 +74,93 @@ where
     C: AsyncRestClient,
 {
     pub fn new(client: &'client C, project: &'client str, repository: &'client str) -> Self {
-        let uri_builder = ResourceUriBuilder::default()
-            .scheme(client.scheme())
-            .host(client.host())
+        let resource_builder = ResourceUriBuilder::default().base_url(client.base_url());
+        let uri_builder = resource_builder
+            .clone()
             .projects()
             .project(project)
             .repos()
             .repository(repository)
             .branches();
+        let branch_utils_uri_builder = resource_builder
+            .branch_utils()
+            .project(project)
+            .repository(repository)
+            .branches();
 
         Self {
             client,
             uri_builder,
+            branch_utils_uri_builder,
         }
     }
 
     pub async fn get_all_branches(&self) -> Result<Vec<Branch>> {
-        let uri = self.uri_builder.build()?;
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.stream_all_branches(PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_branches(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Branch>> {
+        self.stream_branches(&BranchFilter::default(), options)
+    }
+
+    pub async fn get_branches(&self, filter: &BranchFilter) -> Result<Vec<Branch>> {
+        self.stream_branches(filter, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_branches(
+        &self,
+        filter: &BranchFilter,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Branch>> {
+        let builder = filter.apply(self.uri_builder.clone().query());
+        paged(self.client, builder, options)
     }
 
     pub async fn get_default_branch(&self) -> Result<Branch> {
         let uri = self.uri_builder.clone().default().build()?;
         self.client.get_as(&uri).await
     }
+
+    /// Sets the default branch, e.g. `refs/heads/main`.
+    pub async fn set_default_branch(&self, branch: &str) -> Result<()> {
+        let uri = self.uri_builder.clone().default().build()?;
+        let payload = post::DefaultBranch {
+            id: branch.to_owned(),
+        };
+        self.client.put(&uri, Some(&payload)).await
+    }
+
+    /// Creates the branch `name` from `start_point`, which may be a branch, tag or commit.
+    pub async fn create_branch(
+        &self,
+        name: &str,
+        start_point: &str,
+        message: Option<&str>,
+    ) -> Result<Branch> {
+        let uri = self.branch_utils_uri_builder.build()?;
+        let payload = post::Branch {
+            name: name.to_owned(),
+            start_point: start_point.to_owned(),
+            message: message.map(|message| message.to_owned()),
+        };
+        self.client.post(&uri, Some(&payload)).await
+    }
+
+    /// Deletes the branch, only if it still points to `end_point` when one is given.
+    pub async fn delete_branch(&self, name: &str, end_point: Option<&str>) -> Result<()> {
+        let uri = self.branch_utils_uri_builder.build()?;
+        let payload = post::BranchDeletion {
+            name: name.to_owned(),
+            end_point: end_point.map(|end_point| end_point.to_owned()),
+            dry_run: false,
+        };
+        self.client.delete_with(&uri, Some(&payload)).await
+    }
 }
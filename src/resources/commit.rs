@@ -1,6 +1,90 @@
-use crate::uri_builders::{CommitUriBuilder, ResourceUriBuilder, UriBuilder};
-use crate::{models::get::Commit, resources::util::*, traits::AsyncRestClient};
-use anyhow::Result;
+use crate::error::Result;
+use crate::models::get::{Change, Commit, CommitMerges, Diff};
+use crate::uri_builders::{CommitUriBuilder, QueryUriBuilder, ResourceUriBuilder, UriBuilder};
+use crate::{resources::util::*, traits::AsyncRestClient};
+use futures::TryStreamExt;
+
+/// Filters for listing the commits of a repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitFilter {
+    since: Option<String>,
+    until: Option<String>,
+    path: Option<String>,
+    merges: Option<CommitMerges>,
+    follow_renames: Option<bool>,
+    ignore_missing: Option<bool>,
+    with_counts: Option<bool>,
+}
+
+impl CommitFilter {
+    /// Excludes the commits reachable from this branch, tag or commit.
+    pub fn since(mut self, since: &str) -> Self {
+        self.since = Some(since.to_owned());
+        self
+    }
+
+    /// Lists the commits reachable from this branch, tag or commit instead of the default branch.
+    pub fn until(mut self, until: &str) -> Self {
+        self.until = Some(until.to_owned());
+        self
+    }
+
+    /// Only lists the commits which modified the path.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_owned());
+        self
+    }
+
+    pub fn merges(mut self, merges: CommitMerges) -> Self {
+        self.merges = Some(merges);
+        self
+    }
+
+    /// Follows the renames of `path`, which must then be a file.
+    pub fn follow_renames(mut self, follow_renames: bool) -> Self {
+        self.follow_renames = Some(follow_renames);
+        self
+    }
+
+    /// Returns no commits instead of failing when `since` or `until` do not exist.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    pub fn with_counts(mut self, with_counts: bool) -> Self {
+        self.with_counts = Some(with_counts);
+        self
+    }
+
+    fn apply<'r>(
+        &self,
+        mut builder: QueryUriBuilder<CommitUriBuilder<'r>>,
+    ) -> QueryUriBuilder<CommitUriBuilder<'r>> {
+        if let Some(since) = &self.since {
+            builder = builder.since(since);
+        }
+        if let Some(until) = &self.until {
+            builder = builder.until(until);
+        }
+        if let Some(path) = &self.path {
+            builder = builder.path(path);
+        }
+        if let Some(merges) = self.merges {
+            builder = builder.merges(merges);
+        }
+        if let Some(follow_renames) = self.follow_renames {
+            builder = builder.follow_renames(follow_renames);
+        }
+        if let Some(ignore_missing) = self.ignore_missing {
+            builder = builder.ignore_missing(ignore_missing);
+        }
+        if let Some(with_counts) = self.with_counts {
+            builder = builder.with_counts(with_counts);
+        }
+        builder
+    }
+}
 
 pub struct CommitResource<'client, C> {
     client: &'client C,
@@ -13,8 +97,7 @@ where
 {
     pub fn new(client: &'client C, project: &'client str, repository: &'client str) -> Self {
         let uri_builder = ResourceUriBuilder::default()
-            .scheme(client.scheme())
-            .host(client.host())
+            .base_url(client.base_url())
             .projects()
             .project(project)
             .repos()
@@ -28,16 +111,92 @@ where
     }
 
     pub async fn get_all_commits(&self) -> Result<Vec<Commit>> {
-        let uri = self.uri_builder.build()?;
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.stream_all_commits(PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_commits(&self, options: PageOptions) -> Result<PagedStream<'client, Commit>> {
+        self.stream_commits(&CommitFilter::default(), options)
+    }
+
+    pub async fn get_commits(&self, filter: &CommitFilter) -> Result<Vec<Commit>> {
+        self.stream_commits(filter, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_commits(
+        &self,
+        filter: &CommitFilter,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Commit>> {
+        let builder = filter.apply(self.uri_builder.clone().query());
+        paged(self.client, builder, options)
     }
 
     pub async fn get_commit(&self, commit: &str) -> Result<Commit> {
         let uri = self.uri_builder.clone().commit(commit).build()?;
         self.client.get_as(&uri).await
     }
+
+    /// Subscribes the authenticated user to the notifications of the commit.
+    pub async fn watch(&self, commit: &str) -> Result<()> {
+        let uri = self.uri_builder.clone().commit(commit).watch().build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn unwatch(&self, commit: &str) -> Result<()> {
+        let uri = self.uri_builder.clone().commit(commit).watch().build()?;
+        self.client.delete(&uri).await
+    }
+
+    /// Lists the files changed by the commit, compared to `since` or to its first parent otherwise.
+    pub async fn get_commit_changes(
+        &self,
+        commit: &str,
+        since: Option<&str>,
+        changes_options: &ChangesOptions,
+    ) -> Result<Vec<Change>> {
+        self.stream_commit_changes(commit, since, changes_options, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_commit_changes<'a>(
+        &'a self,
+        commit: &'a str,
+        since: Option<&str>,
+        changes_options: &ChangesOptions,
+        options: PageOptions,
+    ) -> Result<PagedStream<'a, Change>> {
+        let builder = self.uri_builder.clone().commit(commit).changes().query();
+        let builder = with_since(builder, since);
+        paged_changes(self.client, builder, changes_options, options)
+    }
+
+    /// Gets the diff of the commit, compared to `since` or to its first parent otherwise.
+    pub async fn get_commit_diff(
+        &self,
+        commit: &str,
+        since: Option<&str>,
+        diff_options: &DiffOptions,
+    ) -> Result<Diff> {
+        let diff = self.uri_builder.clone().commit(commit).diff();
+        let uri = match diff_options.file_path() {
+            Some(path) => with_since(diff_options.apply(diff.path(path).query()), since).build()?,
+            None => with_since(diff_options.apply(diff.query()), since).build()?,
+        };
+        self.client.get_as(&uri).await
+    }
+}
+
+fn with_since<B>(builder: QueryUriBuilder<B>, since: Option<&str>) -> QueryUriBuilder<B>
+where
+    B: UriBuilder,
+{
+    match since {
+        Some(since) => builder.param("since", since),
+        None => builder,
+    }
 }
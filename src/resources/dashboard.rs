@@ -0,0 +1,146 @@
+use crate::error::Result;
+use crate::models::get::{
+    PullRequest, PullRequestCount, PullRequestOrder, PullRequestParticipantStatus, PullRequestRole,
+    PullRequestState,
+};
+use crate::resources::util::{paged, PageOptions, PagedStream};
+use crate::traits::AsyncRestClient;
+use crate::uri_builders::{
+    DashboardPullRequestUriBuilder, QueryUriBuilder, ResourceUriBuilder, UriBuilder,
+};
+use futures::TryStreamExt;
+use std::time::Duration;
+
+/// Filters for the pull requests on the dashboard of the authenticated user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DashboardPullRequestFilter {
+    role: Option<PullRequestRole>,
+    state: Option<PullRequestState>,
+    participant_status: Option<PullRequestParticipantStatus>,
+    closed_since: Option<Duration>,
+    order: Option<PullRequestOrder>,
+}
+
+impl DashboardPullRequestFilter {
+    /// Only returns pull requests in which the user has the given role.
+    pub fn role(mut self, role: PullRequestRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn state(mut self, state: PullRequestState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only returns pull requests in which the user has the given review status.
+    pub fn participant_status(mut self, status: PullRequestParticipantStatus) -> Self {
+        self.participant_status = Some(status);
+        self
+    }
+
+    /// Only returns pull requests closed within the given duration, with a precision of seconds.
+    pub fn closed_since(mut self, closed_since: Duration) -> Self {
+        self.closed_since = Some(closed_since);
+        self
+    }
+
+    pub fn order(mut self, order: PullRequestOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    fn apply<'r>(
+        &self,
+        mut builder: QueryUriBuilder<DashboardPullRequestUriBuilder<'r>>,
+    ) -> QueryUriBuilder<DashboardPullRequestUriBuilder<'r>> {
+        if let Some(role) = &self.role {
+            builder = builder.role(role);
+        }
+        if let Some(state) = &self.state {
+            builder = builder.state(state);
+        }
+        if let Some(status) = &self.participant_status {
+            builder = builder.participant_status(status);
+        }
+        if let Some(closed_since) = self.closed_since {
+            builder = builder.closed_since(closed_since.as_secs());
+        }
+        if let Some(order) = self.order {
+            builder = builder.order(order);
+        }
+        builder
+    }
+}
+
+/// The pull requests of the authenticated user across all repositories.
+pub struct DashboardResource<'client, C> {
+    client: &'client C,
+    uri_builder: ResourceUriBuilder<'client>,
+}
+
+impl<'client, C> DashboardResource<'client, C>
+where
+    C: AsyncRestClient,
+{
+    pub fn new(client: &'client C) -> Self {
+        let uri_builder = ResourceUriBuilder::default().base_url(client.base_url());
+
+        Self {
+            client,
+            uri_builder,
+        }
+    }
+
+    pub async fn get_pull_requests(
+        &self,
+        filter: &DashboardPullRequestFilter,
+    ) -> Result<Vec<PullRequest>> {
+        self.stream_pull_requests(filter, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_pull_requests(
+        &self,
+        filter: &DashboardPullRequestFilter,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        let builder = self.uri_builder.clone().dashboard().pull_requests().query();
+        let builder = filter.apply(builder);
+        paged(self.client, builder, options)
+    }
+
+    /// Lists the open pull requests which await an action of the user, as reviewer by default.
+    pub async fn get_inbox_pull_requests(
+        &self,
+        role: Option<PullRequestRole>,
+    ) -> Result<Vec<PullRequest>> {
+        self.stream_inbox_pull_requests(role, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_inbox_pull_requests(
+        &self,
+        role: Option<PullRequestRole>,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        let mut builder = self.uri_builder.clone().inbox().pull_requests().query();
+        if let Some(role) = &role {
+            builder = builder.role(role);
+        }
+        paged(self.client, builder, options)
+    }
+
+    pub async fn get_inbox_pull_request_count(&self) -> Result<PullRequestCount> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .inbox()
+            .pull_requests()
+            .count()
+            .build()?;
+        self.client.get_as(&uri).await
+    }
+}
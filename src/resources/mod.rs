@@ -1,12 +1,17 @@
 mod branch;
 mod commit;
+mod dashboard;
 mod project;
 mod pull_request;
 mod repository;
+mod tag;
 mod util;
 
 pub use branch::*;
 pub use commit::*;
+pub use dashboard::*;
 pub use project::*;
 pub use pull_request::*;
 pub use repository::*;
+pub use tag::*;
+pub use util::{ChangesOptions, DiffOptions, PageOptions, PagedStream};
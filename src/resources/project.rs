@@ -1,10 +1,11 @@
-use anyhow::Result;
+use crate::error::Result;
 use bytes::Bytes;
+use futures::TryStreamExt;
 
 use crate::uri_builders::{ProjectUriBuilder, ResourceUriBuilder, UriBuilder};
 use crate::{
     models::{get, post},
-    resources::util::accumulate_pages,
+    resources::util::{paged, PageOptions, PagedStream},
     traits::AsyncRestClient,
 };
 
@@ -19,8 +20,7 @@ where
 {
     pub fn new(client: &'client C) -> Self {
         let uri_builder = ResourceUriBuilder::default()
-            .scheme(client.scheme())
-            .host(client.host())
+            .base_url(client.base_url())
             .projects();
 
         Self {
@@ -30,12 +30,17 @@ where
     }
 
     pub async fn get_all_projects(&self) -> Result<Vec<get::Project>> {
-        let uri = self.uri_builder.build()?;
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.stream_all_projects(PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_projects(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, get::Project>> {
+        let builder = self.uri_builder.clone().query();
+        paged(self.client, builder, options)
     }
 
     pub async fn get_project(&self, project: &str) -> Result<get::Project> {
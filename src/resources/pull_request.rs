@@ -1,9 +1,90 @@
-use crate::models::get::{PullRequest, PullRequestState};
+use crate::error::Result;
+use crate::models::get::{
+    Change, Comment, Commit, Diff, PullRequest, PullRequestActivity, PullRequestActivityFromType,
+    PullRequestDirection, PullRequestMember, PullRequestMergeStatus, PullRequestOrder,
+    PullRequestParticipantStatus, PullRequestRole, PullRequestState, Task, TaskCount, TaskState,
+};
 use crate::models::post;
-use crate::resources::util::accumulate_pages;
+use crate::resources::util::{
+    paged, paged_changes, ChangesOptions, DiffOptions, PageOptions, PagedStream,
+};
 use crate::traits::AsyncRestClient;
-use crate::uri_builders::{PullRequestUriBuilder, ResourceUriBuilder, UriBuilder};
-use anyhow::Result;
+use crate::uri_builders::{
+    PullRequestActivitiesUriBuilder, PullRequestUriBuilder, QueryUriBuilder, ResourceUriBuilder,
+    UriBuilder,
+};
+use futures::TryStreamExt;
+use serde::Serialize;
+
+/// Filters for listing the pull requests of a repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestFilter {
+    state: Option<PullRequestState>,
+    direction: Option<PullRequestDirection>,
+    at: Option<String>,
+    order: Option<PullRequestOrder>,
+    with_attributes: Option<bool>,
+    with_properties: Option<bool>,
+}
+
+impl PullRequestFilter {
+    pub fn state(mut self, state: PullRequestState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Whether `at` is the target (incoming) or the source (outgoing) branch.
+    pub fn direction(mut self, direction: PullRequestDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Restricts the pull requests to a branch, e.g. `refs/heads/master`.
+    pub fn at(mut self, branch: &str) -> Self {
+        self.at = Some(branch.to_owned());
+        self
+    }
+
+    pub fn order(mut self, order: PullRequestOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn with_attributes(mut self, with_attributes: bool) -> Self {
+        self.with_attributes = Some(with_attributes);
+        self
+    }
+
+    pub fn with_properties(mut self, with_properties: bool) -> Self {
+        self.with_properties = Some(with_properties);
+        self
+    }
+
+    fn apply<'r>(
+        &self,
+        mut builder: QueryUriBuilder<PullRequestUriBuilder<'r>>,
+    ) -> QueryUriBuilder<PullRequestUriBuilder<'r>> {
+        if let Some(state) = &self.state {
+            builder = builder.state(state);
+        }
+        if let Some(direction) = self.direction {
+            builder = builder.direction(direction);
+        }
+        if let Some(at) = &self.at {
+            builder = builder.at(at);
+        }
+        if let Some(order) = self.order {
+            builder = builder.order(order);
+        }
+        if let Some(with_attributes) = self.with_attributes {
+            builder = builder.with_attributes(with_attributes);
+        }
+        if let Some(with_properties) = self.with_properties {
+            builder = builder.with_properties(with_properties);
+        }
+        builder
+    }
+}
 
 pub struct PullRequestResource<'client, C> {
     client: &'client C,
@@ -16,8 +97,7 @@ where
 {
     pub fn new(client: &'client C, project: &'client str, repository: &'client str) -> Self {
         let uri_builder = ResourceUriBuilder::default()
-            .scheme(client.scheme())
-            .host(client.host())
+            .base_url(client.base_url())
             .projects()
             .project(project)
             .repos()
@@ -30,38 +110,86 @@ where
         }
     }
 
+    pub async fn get_pull_requests(&self, filter: &PullRequestFilter) -> Result<Vec<PullRequest>> {
+        self.stream_pull_requests(filter, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_pull_requests(
+        &self,
+        filter: &PullRequestFilter,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        let builder = filter.apply(self.uri_builder.clone().query());
+        paged(self.client, builder, options)
+    }
+
     pub async fn get_all_pull_requests_with_state(
         &self,
         state: PullRequestState,
     ) -> Result<Vec<PullRequest>> {
-        let uri = format!("{}?state={}", self.uri_builder.build()?, state.as_str());
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.stream_all_pull_requests_with_state(state, PageOptions::default())?
+            .try_collect()
+            .await
     }
 
     pub async fn get_all_pull_requests(&self) -> Result<Vec<PullRequest>> {
-        self.get_all_pull_requests_with_state(PullRequestState::ALL)
+        self.get_all_pull_requests_with_state(PullRequestState::All)
             .await
     }
 
     pub async fn get_all_open_pull_requests(&self) -> Result<Vec<PullRequest>> {
-        self.get_all_pull_requests_with_state(PullRequestState::OPEN)
+        self.get_all_pull_requests_with_state(PullRequestState::Open)
             .await
     }
 
     pub async fn get_all_merged_pull_requests(&self) -> Result<Vec<PullRequest>> {
-        self.get_all_pull_requests_with_state(PullRequestState::MERGED)
+        self.get_all_pull_requests_with_state(PullRequestState::Merged)
             .await
     }
 
     pub async fn get_all_declined_pull_requests(&self) -> Result<Vec<PullRequest>> {
-        self.get_all_pull_requests_with_state(PullRequestState::DECLINED)
+        self.get_all_pull_requests_with_state(PullRequestState::Declined)
             .await
     }
 
+    pub fn stream_all_pull_requests_with_state(
+        &self,
+        state: PullRequestState,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        self.stream_pull_requests(&PullRequestFilter::default().state(state), options)
+    }
+
+    pub fn stream_all_pull_requests(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        self.stream_all_pull_requests_with_state(PullRequestState::All, options)
+    }
+
+    pub fn stream_all_open_pull_requests(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        self.stream_all_pull_requests_with_state(PullRequestState::Open, options)
+    }
+
+    pub fn stream_all_merged_pull_requests(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        self.stream_all_pull_requests_with_state(PullRequestState::Merged, options)
+    }
+
+    pub fn stream_all_declined_pull_requests(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequest>> {
+        self.stream_all_pull_requests_with_state(PullRequestState::Declined, options)
+    }
+
     pub async fn get_pull_request(&self, id: u64) -> Result<PullRequest> {
         let uri = self.uri_builder.clone().pull_request(id).build()?;
         self.client.get_as(&uri).await
@@ -74,4 +202,406 @@ where
         let uri = self.uri_builder.build()?;
         self.client.post(&uri, Some(pull_request)).await
     }
+
+    /// Updates the pull request, failing with [`Error::StaleVersion`](crate::Error::StaleVersion)
+    /// if `version` is not the current version of the pull request.
+    pub async fn update_pull_request(
+        &self,
+        id: u64,
+        version: u32,
+        update: &post::PullRequestUpdate,
+    ) -> Result<PullRequest> {
+        #[derive(Serialize)]
+        struct VersionedUpdate<'u> {
+            version: u32,
+            #[serde(flatten)]
+            update: &'u post::PullRequestUpdate,
+        }
+
+        let uri = self.uri_builder.clone().pull_request(id).build()?;
+        self.client
+            .put(&uri, Some(VersionedUpdate { version, update }))
+            .await
+    }
+
+    /// Checks whether the pull request can be merged and which vetoes prevent it otherwise.
+    pub async fn can_merge(&self, id: u64) -> Result<PullRequestMergeStatus> {
+        let uri = self.uri_builder.clone().pull_request(id).merge().build()?;
+        self.client.get_as(&uri).await
+    }
+
+    /// Merges the pull request, failing with [`Error::StaleVersion`](crate::Error::StaleVersion)
+    /// if `version` is not the current version of the pull request.
+    pub async fn merge_pull_request(&self, id: u64, version: u32) -> Result<PullRequest> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .merge()
+            .query()
+            .version(version)
+            .build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn decline_pull_request(&self, id: u64, version: u32) -> Result<PullRequest> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .decline()
+            .query()
+            .version(version)
+            .build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn reopen_pull_request(&self, id: u64, version: u32) -> Result<PullRequest> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .reopen()
+            .query()
+            .version(version)
+            .build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn approve_pull_request(&self, id: u64) -> Result<PullRequestMember> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .approve()
+            .build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn unapprove_pull_request(&self, id: u64) -> Result<()> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .approve()
+            .build()?;
+        self.client.delete(&uri).await
+    }
+
+    /// Subscribes the authenticated user to the notifications of the pull request.
+    pub async fn watch(&self, id: u64) -> Result<()> {
+        let uri = self.uri_builder.clone().pull_request(id).watch().build()?;
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn unwatch(&self, id: u64) -> Result<()> {
+        let uri = self.uri_builder.clone().pull_request(id).watch().build()?;
+        self.client.delete(&uri).await
+    }
+
+    /// Sets the review status of the authenticated user, who is added as a participant if
+    /// necessary. Bitbucket only allows users to change their own status, so `user_slug` and
+    /// `user_name` must both belong to the authenticated user; the author of the pull request
+    /// cannot review it.
+    pub async fn set_reviewer_status(
+        &self,
+        id: u64,
+        user_slug: &str,
+        user_name: &str,
+        status: PullRequestParticipantStatus,
+    ) -> Result<PullRequestMember> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .participants()
+            .participant(user_slug)
+            .build()?;
+        let update = post::PullRequestParticipantStatusUpdate {
+            user: post::User {
+                name: user_name.to_owned(),
+            },
+            status,
+        };
+        self.client.put(&uri, Some(&update)).await
+    }
+
+    pub async fn get_all_participants(&self, id: u64) -> Result<Vec<PullRequestMember>> {
+        self.stream_all_participants(id, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_participants(
+        &self,
+        id: u64,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequestMember>> {
+        let builder = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .participants()
+            .query();
+        paged(self.client, builder, options)
+    }
+
+    pub async fn add_participant(
+        &self,
+        id: u64,
+        participant: &post::PullRequestParticipant,
+    ) -> Result<PullRequestMember> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .participants()
+            .build()?;
+        self.client.post(&uri, Some(participant)).await
+    }
+
+    pub async fn add_reviewer(&self, id: u64, user_name: &str) -> Result<PullRequestMember> {
+        let participant = post::PullRequestParticipant {
+            user: post::User {
+                name: user_name.to_owned(),
+            },
+            role: PullRequestRole::Reviewer,
+        };
+        self.add_participant(id, &participant).await
+    }
+
+    /// Removes a participant or reviewer from the pull request.
+    pub async fn remove_participant(&self, id: u64, user_slug: &str) -> Result<()> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .participants()
+            .participant(user_slug)
+            .build()?;
+        self.client.delete(&uri).await
+    }
+
+    /// Adds a comment to the pull request, which is posted inline if it has an anchor.
+    pub async fn create_comment(&self, id: u64, comment: &post::Comment) -> Result<Comment> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .comments()
+            .build()?;
+        self.client.post(&uri, Some(comment)).await
+    }
+
+    pub async fn reply_to_comment(&self, id: u64, parent_id: u64, text: &str) -> Result<Comment> {
+        let comment = post::Comment {
+            text: text.to_owned(),
+            parent: Some(post::CommentParent { id: parent_id }),
+            anchor: None,
+        };
+        self.create_comment(id, &comment).await
+    }
+
+    pub async fn get_comment(&self, id: u64, comment_id: u64) -> Result<Comment> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .comments()
+            .comment(comment_id)
+            .build()?;
+        self.client.get_as(&uri).await
+    }
+
+    pub async fn update_comment(
+        &self,
+        id: u64,
+        comment_id: u64,
+        version: u32,
+        text: &str,
+    ) -> Result<Comment> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .comments()
+            .comment(comment_id)
+            .build()?;
+        let update = post::CommentUpdate {
+            version,
+            text: text.to_owned(),
+        };
+        self.client.put(&uri, Some(&update)).await
+    }
+
+    pub async fn delete_comment(&self, id: u64, comment_id: u64, version: u32) -> Result<()> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .comments()
+            .comment(comment_id)
+            .query()
+            .version(version)
+            .build()?;
+        self.client.delete(&uri).await
+    }
+
+    pub async fn get_all_activities(&self, id: u64) -> Result<Vec<PullRequestActivity>> {
+        self.stream_all_activities(id, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_activities(
+        &self,
+        id: u64,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequestActivity>> {
+        let builder = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .activities()
+            .query();
+        self.stream_activities(builder, options)
+    }
+
+    /// Streams the activities starting at the given activity or comment, newest first.
+    pub fn stream_all_activities_from(
+        &self,
+        id: u64,
+        from_id: u64,
+        from_type: PullRequestActivityFromType,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequestActivity>> {
+        let builder = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .activities()
+            .query()
+            .from_id(from_id)
+            .from_type(from_type);
+        self.stream_activities(builder, options)
+    }
+
+    fn stream_activities(
+        &self,
+        builder: QueryUriBuilder<PullRequestActivitiesUriBuilder<'client>>,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, PullRequestActivity>> {
+        paged(self.client, builder, options)
+    }
+
+    pub async fn get_all_tasks(&self, id: u64) -> Result<Vec<Task>> {
+        self.stream_all_tasks(id, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_tasks(
+        &self,
+        id: u64,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Task>> {
+        let builder = self.uri_builder.clone().pull_request(id).tasks().query();
+        paged(self.client, builder, options)
+    }
+
+    pub async fn get_task_count(&self, id: u64) -> Result<TaskCount> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .pull_request(id)
+            .tasks()
+            .count()
+            .build()?;
+        self.client.get_as(&uri).await
+    }
+
+    /// Creates a task on the given comment of the pull request.
+    pub async fn create_task(&self, comment_id: u64, text: &str) -> Result<Task> {
+        let uri = ResourceUriBuilder::default()
+            .base_url(self.client.base_url())
+            .tasks()
+            .build()?;
+        let task = post::Task {
+            anchor: post::TaskAnchor {
+                id: comment_id,
+                r#type: "COMMENT".to_owned(),
+            },
+            text: text.to_owned(),
+        };
+        self.client.post(&uri, Some(&task)).await
+    }
+
+    pub async fn update_task(&self, task_id: u64, update: &post::TaskUpdate) -> Result<Task> {
+        let uri = ResourceUriBuilder::default()
+            .base_url(self.client.base_url())
+            .tasks()
+            .task(task_id)
+            .build()?;
+        self.client.put(&uri, Some(update)).await
+    }
+
+    pub async fn resolve_task(&self, task_id: u64) -> Result<Task> {
+        self.set_task_state(task_id, TaskState::Resolved).await
+    }
+
+    pub async fn reopen_task(&self, task_id: u64) -> Result<Task> {
+        self.set_task_state(task_id, TaskState::Open).await
+    }
+
+    async fn set_task_state(&self, task_id: u64, state: TaskState) -> Result<Task> {
+        let update = post::TaskUpdate {
+            state: Some(state),
+            ..Default::default()
+        };
+        self.update_task(task_id, &update).await
+    }
+
+    pub async fn get_pull_request_commits(&self, id: u64) -> Result<Vec<Commit>> {
+        self.stream_pull_request_commits(id, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_pull_request_commits(
+        &self,
+        id: u64,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Commit>> {
+        let builder = self.uri_builder.clone().pull_request(id).commits().query();
+        paged(self.client, builder, options)
+    }
+
+    pub async fn get_pull_request_changes(
+        &self,
+        id: u64,
+        changes_options: &ChangesOptions,
+    ) -> Result<Vec<Change>> {
+        self.stream_pull_request_changes(id, changes_options, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_pull_request_changes(
+        &self,
+        id: u64,
+        changes_options: &ChangesOptions,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Change>> {
+        let builder = self.uri_builder.clone().pull_request(id).changes().query();
+        paged_changes(self.client, builder, changes_options, options)
+    }
+
+    pub async fn get_pull_request_diff(&self, id: u64, diff_options: &DiffOptions) -> Result<Diff> {
+        let diff = self.uri_builder.clone().pull_request(id).diff();
+        let uri = match diff_options.file_path() {
+            Some(path) => diff_options.apply(diff.path(path).query()).build()?,
+            None => diff_options.apply(diff.query()).build()?,
+        };
+        self.client.get_as(&uri).await
+    }
 }
@@ -1,10 +1,14 @@
+use crate::error::Result;
 use crate::uri_builders::{RepositoryUriBuilder, ResourceUriBuilder, UriBuilder};
 use crate::{
-    models::get::{Repository, Tag},
+    models::{
+        get::{Repository, Tag},
+        post,
+    },
     resources::util::*,
     traits::AsyncRestClient,
 };
-use anyhow::Result;
+use futures::TryStreamExt;
 
 pub struct RepositoryResource<'client, C> {
     client: &'client C,
@@ -17,8 +21,7 @@ where
 {
     pub fn new(client: &'client C, project: &'client str) -> Self {
         let uri_builder = ResourceUriBuilder::default()
-            .scheme(client.scheme())
-            .host(client.host())
+            .base_url(client.base_url())
             .projects()
             .project(project)
             .repos();
@@ -30,12 +33,17 @@ where
     }
 
     pub async fn get_all_repositories(&self) -> Result<Vec<Repository>> {
-        let uri = self.uri_builder.build()?;
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.stream_all_repositories(PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_repositories(
+        &self,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Repository>> {
+        let builder = self.uri_builder.clone().query();
+        paged(self.client, builder, options)
     }
 
     pub async fn get_repository(&self, repository: &str) -> Result<Repository> {
@@ -44,16 +52,113 @@ where
     }
 
     pub async fn get_all_repository_tags(&self, repository: &str) -> Result<Vec<Tag>> {
-        let uri = self
+        self.stream_all_repository_tags(repository, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_all_repository_tags<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedStream<'a, Tag>> {
+        let builder = self
             .uri_builder
             .clone()
             .repository(repository)
             .tags()
+            .query();
+        paged(self.client, builder, options)
+    }
+
+    pub async fn create_repository(&self, repository: &post::Repository) -> Result<Repository> {
+        let uri = self.uri_builder.build()?;
+        self.client.post(&uri, Some(repository)).await
+    }
+
+    /// Updates the repository, setting a `project` moves it into that project.
+    pub async fn update_repository(
+        &self,
+        repository: &str,
+        payload: &post::Repository,
+    ) -> Result<Repository> {
+        let uri = self.uri_builder.clone().repository(repository).build()?;
+        self.client.put(&uri, Some(payload)).await
+    }
+
+    /// Schedules the repository for deletion.
+    pub async fn delete_repository(&self, repository: &str) -> Result<()> {
+        let uri = self.uri_builder.clone().repository(repository).build()?;
+        self.client.delete(&uri).await
+    }
+
+    /// Forks the repository into the given project, named `name` or after the origin otherwise.
+    pub async fn fork_repository(
+        &self,
+        repository: &str,
+        project: &str,
+        name: Option<&str>,
+    ) -> Result<Repository> {
+        let uri = self.uri_builder.clone().repository(repository).build()?;
+        let fork = post::Repository {
+            name: name.map(|name| name.to_owned()),
+            project: Some(post::RepositoryProject {
+                key: project.to_owned(),
+            }),
+            ..Default::default()
+        };
+        self.client.post(&uri, Some(&fork)).await
+    }
+
+    /// Recreates the storage of a repository which failed to be initialised.
+    pub async fn recreate_repository(&self, repository: &str) -> Result<Repository> {
+        let uri = self
+            .uri_builder
+            .clone()
+            .repository(repository)
+            .recreate()
             .build()?;
-        accumulate_pages(&uri, |uri| {
-            let uri = uri.to_owned();
-            async move { self.client.get_as(&uri).await }
-        })
-        .await
+        self.client.post(&uri, None::<()>).await
+    }
+
+    pub async fn get_repository_forks(&self, repository: &str) -> Result<Vec<Repository>> {
+        self.stream_repository_forks(repository, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_repository_forks<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedStream<'a, Repository>> {
+        let builder = self
+            .uri_builder
+            .clone()
+            .repository(repository)
+            .forks()
+            .query();
+        paged(self.client, builder, options)
+    }
+
+    /// Lists the repositories which share the origin of the repository, excluding itself.
+    pub async fn get_related_repositories(&self, repository: &str) -> Result<Vec<Repository>> {
+        self.stream_related_repositories(repository, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_related_repositories<'a>(
+        &'a self,
+        repository: &'a str,
+        options: PageOptions,
+    ) -> Result<PagedStream<'a, Repository>> {
+        let builder = self
+            .uri_builder
+            .clone()
+            .repository(repository)
+            .related()
+            .query();
+        paged(self.client, builder, options)
     }
 }
@@ -0,0 +1,125 @@
+use crate::error::Result;
+use crate::models::{
+    get::{Tag, TagOrder},
+    post,
+};
+use crate::uri_builders::{
+    GitTagUriBuilder, QueryUriBuilder, ResourceUriBuilder, TagUriBuilder, UriBuilder,
+};
+use crate::{resources::util::*, traits::AsyncRestClient};
+use futures::TryStreamExt;
+
+/// Filters for listing the tags of a repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagFilter {
+    filter_text: Option<String>,
+    order_by: Option<TagOrder>,
+}
+
+impl TagFilter {
+    pub fn filter_text(mut self, filter_text: &str) -> Self {
+        self.filter_text = Some(filter_text.to_owned());
+        self
+    }
+
+    pub fn order_by(mut self, order_by: TagOrder) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    fn apply<'r>(
+        &self,
+        mut builder: QueryUriBuilder<TagUriBuilder<'r>>,
+    ) -> QueryUriBuilder<TagUriBuilder<'r>> {
+        if let Some(filter_text) = &self.filter_text {
+            builder = builder.filter_text(filter_text);
+        }
+        if let Some(order_by) = &self.order_by {
+            builder = builder.order_by(order_by);
+        }
+        builder
+    }
+}
+
+pub struct TagResource<'client, C> {
+    client: &'client C,
+    uri_builder: TagUriBuilder<'client>,
+    git_uri_builder: GitTagUriBuilder<'client>,
+}
+
+impl<'client, C> TagResource<'client, C>
+where
+    C: AsyncRestClient,
+{
+    pub fn new(client: &'client C, project: &'client str, repository: &'client str) -> Self {
+        let resource_builder = ResourceUriBuilder::default().base_url(client.base_url());
+        let uri_builder = resource_builder
+            .clone()
+            .projects()
+            .project(project)
+            .repos()
+            .repository(repository)
+            .tags();
+        let git_uri_builder = resource_builder
+            .git()
+            .project(project)
+            .repository(repository)
+            .tags();
+
+        Self {
+            client,
+            uri_builder,
+            git_uri_builder,
+        }
+    }
+
+    pub async fn get_tags(&self, filter: &TagFilter) -> Result<Vec<Tag>> {
+        self.stream_tags(filter, PageOptions::default())?
+            .try_collect()
+            .await
+    }
+
+    pub fn stream_tags(
+        &self,
+        filter: &TagFilter,
+        options: PageOptions,
+    ) -> Result<PagedStream<'client, Tag>> {
+        let builder = filter.apply(self.uri_builder.clone().query());
+        paged(self.client, builder, options)
+    }
+
+    pub async fn get_tag(&self, name: &str) -> Result<Tag> {
+        let uri = self.uri_builder.clone().tag(name).build()?;
+        self.client.get_as(&uri).await
+    }
+
+    /// Creates a lightweight tag at `start_point`, which may be a branch, tag or commit.
+    pub async fn create_tag(&self, name: &str, start_point: &str) -> Result<Tag> {
+        self.post_tag(name, start_point, None).await
+    }
+
+    /// Creates an annotated tag with the given message at `start_point`.
+    pub async fn create_annotated_tag(
+        &self,
+        name: &str,
+        start_point: &str,
+        message: &str,
+    ) -> Result<Tag> {
+        self.post_tag(name, start_point, Some(message)).await
+    }
+
+    async fn post_tag(&self, name: &str, start_point: &str, message: Option<&str>) -> Result<Tag> {
+        let uri = self.uri_builder.build()?;
+        let payload = post::Tag {
+            name: name.to_owned(),
+            start_point: start_point.to_owned(),
+            message: message.map(|message| message.to_owned()),
+        };
+        self.client.post(&uri, Some(&payload)).await
+    }
+
+    pub async fn delete_tag(&self, name: &str) -> Result<()> {
+        let uri = self.git_uri_builder.clone().tag(name).build()?;
+        self.client.delete(&uri).await
+    }
+}
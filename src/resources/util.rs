@@ -1,25 +1,287 @@
-use crate::models::get::PagedResponse;
-use anyhow::Result;
+use crate::error::Result;
+use crate::models::get::{Change, PagedResponse};
+use crate::traits::AsyncRestClient;
+use crate::uri_builders::{QueryUriBuilder, UriBuilder};
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::convert::TryFrom;
 use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
-pub async fn accumulate_pages<F, Fut, R: std::fmt::Debug>(uri: &str, method: F) -> Result<Vec<R>>
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    page_size: u32,
+    start: u32,
+    max_items: Option<usize>,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            start: 0,
+            max_items: None,
+        }
+    }
+}
+
+impl PageOptions {
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    pub fn start(mut self, start: u32) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+/// Options for the diffs of pull requests and commits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffOptions {
+    context_lines: Option<u32>,
+    ignore_whitespace: bool,
+    path: Option<String>,
+    with_comments: Option<bool>,
+}
+
+impl DiffOptions {
+    pub fn context_lines(mut self, context_lines: u32) -> Self {
+        self.context_lines = Some(context_lines);
+        self
+    }
+
+    pub fn ignore_whitespace(mut self, ignore_whitespace: bool) -> Self {
+        self.ignore_whitespace = ignore_whitespace;
+        self
+    }
+
+    /// Restricts the diff to a single file.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.trim_matches('/').to_owned());
+        self
+    }
+
+    pub fn with_comments(mut self, with_comments: bool) -> Self {
+        self.with_comments = Some(with_comments);
+        self
+    }
+
+    pub(crate) fn file_path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    pub(crate) fn apply<B>(&self, mut builder: QueryUriBuilder<B>) -> QueryUriBuilder<B>
+    where
+        B: UriBuilder,
+    {
+        if let Some(context_lines) = self.context_lines {
+            builder = builder.param("contextLines", context_lines);
+        }
+        if self.ignore_whitespace {
+            builder = builder.param("whitespace", "ignore-all");
+        }
+        if let Some(with_comments) = self.with_comments {
+            builder = builder.param("withComments", with_comments);
+        }
+        builder
+    }
+}
+
+/// Options for the changes of pull requests and commits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangesOptions {
+    path: Option<String>,
+    with_comments: Option<bool>,
+}
+
+impl ChangesOptions {
+    /// Restricts the changes to the file or the directory at `path`.
+    ///
+    /// The changes endpoints can't filter by path, so the other changes are skipped by the client.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.trim_matches('/').to_owned());
+        self
+    }
+
+    pub fn with_comments(mut self, with_comments: bool) -> Self {
+        self.with_comments = Some(with_comments);
+        self
+    }
+
+    pub(crate) fn apply<B>(&self, builder: QueryUriBuilder<B>) -> QueryUriBuilder<B>
+    where
+        B: UriBuilder,
+    {
+        match self.with_comments {
+            Some(with_comments) => builder.param("withComments", with_comments),
+            None => builder,
+        }
+    }
+}
+
+/// Streams the items of the paged endpoint at `builder`, failing early if its URI is invalid.
+pub(crate) fn paged<'a, C, B, T>(
+    client: &'a C,
+    builder: QueryUriBuilder<B>,
+    options: PageOptions,
+) -> Result<PagedStream<'a, T>>
 where
-    F: Fn(&str) -> Fut,
-    Fut: Future<Output = Result<PagedResponse<R>>>,
+    C: AsyncRestClient,
+    B: UriBuilder + Clone + Send + Sync + 'a,
+    T: DeserializeOwned + Send + 'a,
 {
-    let limit = 50u32;
-    let base_uri = format!("{}?limit={}", uri, limit);
-    let mut res = Vec::new();
+    builder.build()?;
+    Ok(PagedStream::new(builder, options, move |uri| async move {
+        client.get_as(&uri).await
+    }))
+}
 
-    let mut uri = base_uri.clone();
-    loop {
-        let page: PagedResponse<_> = method(&uri).await?;
-        res.extend(page.values);
+/// Streams the changes at `builder`, skipping those outside of the path of `changes_options`.
+pub(crate) fn paged_changes<'a, C, B>(
+    client: &'a C,
+    builder: QueryUriBuilder<B>,
+    changes_options: &ChangesOptions,
+    options: PageOptions,
+) -> Result<PagedStream<'a, Change>>
+where
+    C: AsyncRestClient,
+    B: UriBuilder + Clone + Send + Sync + 'a,
+{
+    let builder = changes_options.apply(builder);
+    let path = match changes_options.path.clone() {
+        Some(path) if !path.is_empty() => path,
+        _ => return paged(client, builder, options),
+    };
 
-        if page.is_last_page {
-            break Ok(res);
+    builder.build()?;
+    Ok(PagedStream::new(builder, options, move |uri| {
+        let path = path.clone();
+        async move {
+            let mut page: PagedResponse<Change> = client.get_as(&uri).await?;
+            page.values.retain(|change| is_below(change, &path));
+            Ok(page)
         }
+    }))
+}
+
+fn is_below(change: &Change, path: &str) -> bool {
+    let mut paths = std::iter::once(&change.path).chain(change.src_path.as_ref());
+
+    paths.any(|change_path| match change_path.path.strip_prefix(path) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    })
+}
+
+struct PageState {
+    next_start: Option<u32>,
+    remaining: Option<usize>,
+}
+
+/// A stream over the items of a paged endpoint.
+///
+/// Pages are only requested once the items of the previous page have been consumed.
+pub struct PagedStream<'a, T> {
+    inner: BoxStream<'a, Result<T>>,
+}
+
+impl<'a, T> PagedStream<'a, T>
+where
+    T: Send + 'a,
+{
+    pub fn new<B, F, Fut>(builder: QueryUriBuilder<B>, options: PageOptions, fetch: F) -> Self
+    where
+        B: UriBuilder + Clone + Send + Sync + 'a,
+        F: Fn(String) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<PagedResponse<T>>> + Send + 'a,
+    {
+        let state = PageState {
+            next_start: Some(options.start),
+            remaining: options.max_items,
+        };
+
+        let pages = stream::try_unfold(state, move |state| {
+            let request = match (state.next_start, state.remaining) {
+                (None, _) | (_, Some(0)) => None,
+                (Some(start), remaining) => {
+                    let limit = match remaining {
+                        Some(remaining) => options
+                            .page_size
+                            .min(u32::try_from(remaining).unwrap_or(u32::MAX)),
+                        None => options.page_size,
+                    };
+                    Some(page_uri(&builder, start, limit).map(&fetch))
+                }
+            };
+
+            next_page(request, state)
+        });
 
-        uri = format!("{}&start={}", base_uri, page.next_page_start.unwrap());
+        let inner = pages
+            .map_ok(|values| stream::iter(values.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed();
+
+        Self { inner }
+    }
+}
+
+impl<'a, T> Stream for PagedStream<'a, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
     }
 }
+
+async fn next_page<T, Fut>(
+    request: Option<Result<Fut>>,
+    mut state: PageState,
+) -> Result<Option<(Vec<T>, PageState)>>
+where
+    Fut: Future<Output = Result<PagedResponse<T>>>,
+{
+    let page = match request {
+        Some(request) => request?.await?,
+        None => return Ok(None),
+    };
+
+    let mut values = page.values;
+
+    if let Some(remaining) = state.remaining.as_mut() {
+        values.truncate(*remaining);
+        *remaining -= values.len();
+    }
+
+    state.next_start = if page.is_last_page {
+        None
+    } else {
+        page.next_page_start
+    };
+
+    Ok(Some((values, state)))
+}
+
+fn page_uri<B>(builder: &QueryUriBuilder<B>, start: u32, limit: u32) -> Result<String>
+where
+    B: UriBuilder + Clone,
+{
+    let builder = builder.clone().limit(limit);
+    let uri = if start == 0 {
+        builder.build()?
+    } else {
+        builder.start(start).build()?
+    };
+
+    Ok(uri)
+}
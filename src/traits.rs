@@ -1,5 +1,5 @@
-use crate::Scheme;
-use anyhow::Result;
+use crate::error::Result;
+use crate::{BaseUrl, Scheme};
 use async_trait::async_trait;
 use reqwest::Response;
 use serde::{de::DeserializeOwned, Serialize};
@@ -9,10 +9,16 @@ pub trait Payload: Serialize + Send + Sync {}
 impl<T> Payload for T where T: Serialize + Send + Sync {}
 
 #[async_trait]
-pub trait AsyncRestClient {
-    fn host(&self) -> &str;
+pub trait AsyncRestClient: Send + Sync {
+    fn base_url(&self) -> &BaseUrl;
 
-    fn scheme(&self) -> &Scheme;
+    fn host(&self) -> &str {
+        self.base_url().host()
+    }
+
+    fn scheme(&self) -> &Scheme {
+        self.base_url().scheme()
+    }
 
     async fn get(&self, uri: &str) -> Result<Response>;
 
@@ -31,6 +37,11 @@ pub trait AsyncRestClient {
         P: Payload;
 
     async fn delete(&self, uri: &str) -> Result<()>;
+
+    /// Deletes with a request body, for the endpoints which take the resource in the payload.
+    async fn delete_with<P>(&self, uri: &str, payload: Option<P>) -> Result<()>
+    where
+        P: Payload;
 }
 
 #[async_trait]
@@ -38,12 +49,8 @@ impl<C> AsyncRestClient for Box<C>
 where
     C: AsyncRestClient + ?Sized + Sync + Send,
 {
-    fn host(&self) -> &str {
-        (**self).host()
-    }
-
-    fn scheme(&self) -> &Scheme {
-        (**self).scheme()
+    fn base_url(&self) -> &BaseUrl {
+        (**self).base_url()
     }
 
     async fn get(&self, uri: &str) -> Result<Response> {
@@ -76,4 +83,11 @@ where
     async fn delete(&self, uri: &str) -> Result<()> {
         (**self).delete(uri).await
     }
+
+    async fn delete_with<P>(&self, uri: &str, payload: Option<P>) -> Result<()>
+    where
+        P: Payload,
+    {
+        (**self).delete_with(uri, payload).await
+    }
 }
@@ -0,0 +1,80 @@
+use crate::uri_builders::encode::encode_segment;
+use crate::uri_builders::{BuildResult, ResourceUriBuilder, TerminalUriBuilder, UriBuilder};
+
+/// The root of the personal access tokens API, `rest/access-tokens/1.0`.
+#[derive(Debug, Clone)]
+pub struct AccessTokensUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> AccessTokensUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn user(self, user_slug: &'r str) -> AccessTokenUserUriBuilder<'r> {
+        AccessTokenUserUriBuilder::new(self, user_slug)
+    }
+}
+
+impl<'r> UriBuilder for AccessTokensUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessTokenUserUriBuilder<'r> {
+    builder: AccessTokensUriBuilder<'r>,
+    user_slug: &'r str,
+}
+
+impl<'r> AccessTokenUserUriBuilder<'r> {
+    pub fn new(builder: AccessTokensUriBuilder<'r>, user_slug: &'r str) -> Self {
+        Self { builder, user_slug }
+    }
+
+    pub fn token(self, token_id: u64) -> TerminalUriBuilder<Self> {
+        TerminalUriBuilder::new(self, token_id.to_string())
+    }
+}
+
+impl<'r> UriBuilder for AccessTokenUserUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!(
+            "{}/users/{}",
+            self.builder.build()?,
+            encode_segment(self.user_slug)?
+        );
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::TEST_HOST;
+
+    fn base_uri() -> String {
+        format!("http://{}/rest/access-tokens/1.0", TEST_HOST)
+    }
+
+    fn builder<'a>() -> AccessTokenUserUriBuilder<'a> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .access_tokens()
+            .user("jdoe")
+    }
+
+    #[test]
+    fn access_token_user_uri_works() {
+        let uri = builder().build();
+        assert_uri!(uri, format!("{}/users/jdoe", base_uri()));
+    }
+
+    #[test]
+    fn access_token_uri_works() {
+        let uri = builder().token(42).build();
+        assert_uri!(uri, format!("{}/users/jdoe/42", base_uri()));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::uri_builders::encode::encode_segment;
+use crate::uri_builders::{BuildResult, UriBuilder};
+
+/// The REST API families of Bitbucket Server, each served under its own `rest/<name>/<version>` root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestApi {
+    Core,
+    AccessTokens,
+    BranchUtils,
+    BuildStatus,
+    DefaultReviewers,
+    Git,
+    Insights,
+    Keys,
+    Search,
+    Ssh,
+}
+
+impl RestApi {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RestApi::Core => "api",
+            RestApi::AccessTokens => "access-tokens",
+            RestApi::BranchUtils => "branch-utils",
+            RestApi::BuildStatus => "build-status",
+            RestApi::DefaultReviewers => "default-reviewers",
+            RestApi::Git => "git",
+            RestApi::Insights => "insights",
+            RestApi::Keys => "keys",
+            RestApi::Search => "search",
+            RestApi::Ssh => "ssh",
+        }
+    }
+
+    pub fn default_version(&self) -> &'static str {
+        match self {
+            RestApi::Search => "latest",
+            _ => "1.0",
+        }
+    }
+}
+
+/// A project below the root of a REST API family, e.g. `rest/default-reviewers/1.0/projects/PRJ`.
+///
+/// Each family adds the paths it serves for projects with its own `impl` block.
+#[derive(Debug, Clone)]
+pub struct ApiProjectUriBuilder<'r, A> {
+    builder: A,
+    project: &'r str,
+}
+
+impl<'r, A> ApiProjectUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    pub fn new(builder: A, project: &'r str) -> Self {
+        Self { builder, project }
+    }
+
+    pub fn repository(self, repository: &'r str) -> ApiRepositoryUriBuilder<'r, A> {
+        ApiRepositoryUriBuilder::new(self, repository)
+    }
+}
+
+impl<'r, A> UriBuilder for ApiProjectUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    fn build(&self) -> BuildResult {
+        let uri = format!(
+            "{}/projects/{}",
+            self.builder.build()?,
+            encode_segment(self.project)?
+        );
+        Ok(uri)
+    }
+}
+
+/// A repository below the root of a REST API family.
+#[derive(Debug, Clone)]
+pub struct ApiRepositoryUriBuilder<'r, A> {
+    builder: ApiProjectUriBuilder<'r, A>,
+    repository: &'r str,
+}
+
+impl<'r, A> ApiRepositoryUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    pub fn new(builder: ApiProjectUriBuilder<'r, A>, repository: &'r str) -> Self {
+        Self {
+            builder,
+            repository,
+        }
+    }
+}
+
+impl<'r, A> UriBuilder for ApiRepositoryUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    fn build(&self) -> BuildResult {
+        let uri = format!(
+            "{}/repos/{}",
+            self.builder.build()?,
+            encode_segment(self.repository)?
+        );
+        Ok(uri)
+    }
+}
+
+/// A commit of a repository below the root of a REST API family.
+#[derive(Debug, Clone)]
+pub struct ApiCommitUriBuilder<'r, A> {
+    builder: ApiRepositoryUriBuilder<'r, A>,
+    commit_id: &'r str,
+}
+
+impl<'r, A> ApiCommitUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    pub fn new(builder: ApiRepositoryUriBuilder<'r, A>, commit_id: &'r str) -> Self {
+        Self { builder, commit_id }
+    }
+}
+
+impl<'r, A> UriBuilder for ApiCommitUriBuilder<'r, A>
+where
+    A: UriBuilder,
+{
+    fn build(&self) -> BuildResult {
+        let uri = format!(
+            "{}/commits/{}",
+            self.builder.build()?,
+            encode_segment(self.commit_id)?
+        );
+        Ok(uri)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::uri_builders::{BuildResult, UriBuilder, WithRepositoryUriBuilder};
+use crate::models::get::BranchOrder;
+use crate::uri_builders::{BuildResult, QueryUriBuilder, UriBuilder, WithRepositoryUriBuilder};
 
 #[derive(Debug, Clone)]
 pub struct BranchUriBuilder<'r> {
@@ -20,6 +21,24 @@ impl<'r> UriBuilder for BranchUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<BranchUriBuilder<'r>> {
+    pub fn filter_text(self, filter_text: &str) -> Self {
+        self.param("filterText", filter_text)
+    }
+
+    pub fn base(self, base: &str) -> Self {
+        self.param("base", base)
+    }
+
+    pub fn details(self, details: bool) -> Self {
+        self.param("details", details)
+    }
+
+    pub fn order_by(self, order_by: BranchOrder) -> Self {
+        self.param("orderBy", order_by.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,6 +70,24 @@ mod tests {
         assert_uri!(uri, base_uri());
     }
 
+    #[test]
+    fn branch_query_uri_works() {
+        let uri = builder()
+            .query()
+            .filter_text("release/")
+            .base("refs/heads/master")
+            .details(true)
+            .order_by(BranchOrder::Modification)
+            .build();
+        assert_uri!(
+            uri,
+            format!(
+                "{}?filterText=release%2F&base=refs%2Fheads%2Fmaster&details=true&orderBy=MODIFICATION",
+                base_uri()
+            )
+        );
+    }
+
     #[test]
     fn branch_default_uri_works() {
         let uri = builder().default().build();
@@ -0,0 +1,53 @@
+use crate::uri_builders::{
+    ApiProjectUriBuilder, ApiRepositoryUriBuilder, BuildResult, ResourceUriBuilder, UriBuilder,
+};
+
+/// The root of the branch utilities API, `rest/branch-utils/1.0`.
+#[derive(Debug, Clone)]
+pub struct BranchUtilsUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> BranchUtilsUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn project(self, project: &'r str) -> ApiProjectUriBuilder<'r, Self> {
+        ApiProjectUriBuilder::new(self, project)
+    }
+}
+
+impl<'r> UriBuilder for BranchUtilsUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+impl<'r> ApiRepositoryUriBuilder<'r, BranchUtilsUriBuilder<'r>> {
+    terminal_resource_fn!(branches);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+
+    #[test]
+    fn branch_utils_branches_uri_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .branch_utils()
+            .project(TEST_PROJECT)
+            .repository(TEST_REPO)
+            .branches()
+            .build();
+        assert_uri!(
+            uri,
+            format!(
+                "http://{}/rest/branch-utils/1.0/projects/{}/repos/{}/branches",
+                TEST_HOST, TEST_PROJECT, TEST_REPO
+            )
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use crate::uri_builders::path::PathUriBuilder;
-use crate::uri_builders::{BuildResult, TerminalUriBuilder, UriBuilder};
+use crate::uri_builders::path::{PathUriBuilder, WithPathUriBuilder};
+use crate::uri_builders::{BuildResult, UriBuilder};
 
 #[derive(Debug, Clone)]
 pub struct BrowseUriBuilder<B> {
@@ -15,7 +15,7 @@ where
         Self { builder }
     }
 
-    pub fn path(self, path: &str) -> TerminalUriBuilder<PathUriBuilder<'_, B>> {
+    pub fn path(self, path: &str) -> WithPathUriBuilder<PathUriBuilder<'static, B>> {
         self.builder.path(path)
     }
 }
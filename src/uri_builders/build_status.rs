@@ -0,0 +1,95 @@
+use crate::uri_builders::{BuildResult, PathUriBuilder, ResourceUriBuilder, UriBuilder};
+
+/// The root of the build status API, `rest/build-status/1.0`.
+#[derive(Debug, Clone)]
+pub struct BuildStatusUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> BuildStatusUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn commits(self) -> BuildStatusCommitUriBuilder<'r> {
+        BuildStatusCommitUriBuilder::new(self)
+    }
+}
+
+impl<'r> UriBuilder for BuildStatusUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BuildStatusCommitUriBuilder<'r> {
+    builder: BuildStatusUriBuilder<'r>,
+}
+
+impl<'r> BuildStatusCommitUriBuilder<'r> {
+    pub fn new(builder: BuildStatusUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    /// The build statuses of a commit.
+    pub fn commit(self, commit_id: &'r str) -> PathUriBuilder<'r, Self> {
+        PathUriBuilder::new(self, commit_id)
+    }
+
+    /// The counts of the successful, in progress and failed builds of a commit.
+    pub fn stats(self, commit_id: &'r str) -> PathUriBuilder<'r, PathUriBuilder<'static, Self>> {
+        PathUriBuilder::new(PathUriBuilder::new(self, "stats"), commit_id)
+    }
+}
+
+impl<'r> UriBuilder for BuildStatusCommitUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/commits", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::TEST_HOST;
+
+    fn base_uri() -> String {
+        format!("http://{}/rest/build-status/1.0/commits", TEST_HOST)
+    }
+
+    fn builder<'a>() -> BuildStatusCommitUriBuilder<'a> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .build_status()
+            .commits()
+    }
+
+    #[test]
+    fn build_status_commit_uri_works() {
+        let uri = builder().commit("abc123").build();
+        assert_uri!(uri, format!("{}/abc123", base_uri()));
+    }
+
+    #[test]
+    fn build_status_stats_uri_works() {
+        let uri = builder().stats("abc123").build();
+        assert_uri!(uri, format!("{}/stats/abc123", base_uri()));
+    }
+
+    #[test]
+    fn build_status_uri_with_version_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .version("2.0")
+            .build_status()
+            .commits()
+            .commit("abc123")
+            .build();
+        assert_uri!(
+            uri,
+            format!("http://{}/rest/build-status/2.0/commits/abc123", TEST_HOST)
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use crate::models::get::CommitMerges;
+use crate::uri_builders::encode::encode_segment;
 use crate::uri_builders::{
-    BuildResult, DiffUriBuilder, TerminalUriBuilder, UriBuilder, WithRepositoryUriBuilder,
+    BuildResult, DiffUriBuilder, QueryUriBuilder, TerminalUriBuilder, UriBuilder,
+    WithRepositoryUriBuilder,
 };
 
 #[derive(Debug, Clone)]
@@ -24,6 +27,36 @@ impl<'r> UriBuilder for CommitUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<CommitUriBuilder<'r>> {
+    pub fn since(self, commit: &str) -> Self {
+        self.param("since", commit)
+    }
+
+    pub fn until(self, commit: &str) -> Self {
+        self.param("until", commit)
+    }
+
+    pub fn path(self, path: &str) -> Self {
+        self.param("path", path)
+    }
+
+    pub fn merges(self, merges: CommitMerges) -> Self {
+        self.param("merges", merges.as_str())
+    }
+
+    pub fn follow_renames(self, follow_renames: bool) -> Self {
+        self.param("followRenames", follow_renames)
+    }
+
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        self.param("ignoreMissing", ignore_missing)
+    }
+
+    pub fn with_counts(self, with_counts: bool) -> Self {
+        self.param("withCounts", with_counts)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WithCommitUriBuilder<'r> {
     builder: CommitUriBuilder<'r>,
@@ -49,7 +82,11 @@ impl<'r> WithCommitUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithCommitUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.commit_id);
+        let uri = format!(
+            "{}/{}",
+            self.builder.build()?,
+            encode_segment(self.commit_id)?
+        );
         Ok(uri)
     }
 }
@@ -120,6 +157,34 @@ mod tests {
         assert_uri!(uri, base_uri());
     }
 
+    #[test]
+    fn commit_query_uri_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .project(TEST_PROJECT)
+            .repos()
+            .repository(TEST_REPO)
+            .commits()
+            .query()
+            .since("v1.0")
+            .until("refs/heads/release/2.0")
+            .path("src/main file.rs")
+            .merges(CommitMerges::Exclude)
+            .follow_renames(true)
+            .ignore_missing(true)
+            .with_counts(false)
+            .build();
+
+        assert_uri!(
+            uri,
+            format!(
+                "{}?since=v1.0&until=refs%2Fheads%2Frelease%2F2.0&path=src%2Fmain%20file.rs&merges=exclude&followRenames=true&ignoreMissing=true&withCounts=false",
+                base_uri()
+            )
+        );
+    }
+
     #[test]
     fn with_commit_uri_works() {
         let uri = builder().build();
@@ -0,0 +1,178 @@
+use crate::models::get::{
+    PullRequestOrder, PullRequestParticipantStatus, PullRequestRole, PullRequestState,
+};
+use crate::uri_builders::{BuildResult, QueryUriBuilder, ResourceUriBuilder, UriBuilder};
+
+#[derive(Debug, Clone)]
+pub struct DashboardUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> DashboardUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn pull_requests(self) -> DashboardPullRequestUriBuilder<'r> {
+        DashboardPullRequestUriBuilder::new(self)
+    }
+}
+
+impl<'r> UriBuilder for DashboardUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/dashboard", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DashboardPullRequestUriBuilder<'r> {
+    builder: DashboardUriBuilder<'r>,
+}
+
+impl<'r> DashboardPullRequestUriBuilder<'r> {
+    pub fn new(builder: DashboardUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+}
+
+impl<'r> UriBuilder for DashboardPullRequestUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/pull-requests", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+impl<'r> QueryUriBuilder<DashboardPullRequestUriBuilder<'r>> {
+    pub fn state(self, state: &PullRequestState) -> Self {
+        self.param("state", state.as_str())
+    }
+
+    pub fn role(self, role: &PullRequestRole) -> Self {
+        self.param("role", role.as_str())
+    }
+
+    pub fn participant_status(self, status: &PullRequestParticipantStatus) -> Self {
+        self.param("participantStatus", status.as_str())
+    }
+
+    /// Only returns pull requests closed within the given number of seconds.
+    pub fn closed_since(self, seconds: u64) -> Self {
+        self.param("closedSince", seconds)
+    }
+
+    pub fn order(self, order: PullRequestOrder) -> Self {
+        self.param("order", order.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InboxUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> InboxUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn pull_requests(self) -> InboxPullRequestUriBuilder<'r> {
+        InboxPullRequestUriBuilder::new(self)
+    }
+}
+
+impl<'r> UriBuilder for InboxUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/inbox", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InboxPullRequestUriBuilder<'r> {
+    builder: InboxUriBuilder<'r>,
+}
+
+impl<'r> InboxPullRequestUriBuilder<'r> {
+    pub fn new(builder: InboxUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    terminal_resource_fn!(count);
+}
+
+impl<'r> UriBuilder for InboxPullRequestUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/pull-requests", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+impl<'r> QueryUriBuilder<InboxPullRequestUriBuilder<'r>> {
+    pub fn role(self, role: &PullRequestRole) -> Self {
+        self.param("role", role.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{self, TEST_HOST};
+
+    fn builder<'a>() -> ResourceUriBuilder<'a> {
+        ResourceUriBuilder::default().host(TEST_HOST)
+    }
+
+    #[test]
+    fn dashboard_pull_requests_uri_works() {
+        let uri = builder().dashboard().pull_requests().build();
+        assert_uri!(
+            uri,
+            format!("{}/dashboard/pull-requests", tests::base_uri())
+        );
+    }
+
+    #[test]
+    fn dashboard_pull_requests_query_uri_works() {
+        let uri = builder()
+            .dashboard()
+            .pull_requests()
+            .query()
+            .role(&PullRequestRole::Reviewer)
+            .state(&PullRequestState::Merged)
+            .participant_status(&PullRequestParticipantStatus::NeedsWork)
+            .closed_since(86400)
+            .order(PullRequestOrder::Oldest)
+            .build();
+        assert_uri!(
+            uri,
+            format!(
+                "{}/dashboard/pull-requests?role=REVIEWER&state=MERGED&participantStatus=NEEDS_WORK&closedSince=86400&order=OLDEST",
+                tests::base_uri()
+            )
+        );
+    }
+
+    #[test]
+    fn inbox_pull_requests_uri_works() {
+        let uri = builder()
+            .inbox()
+            .pull_requests()
+            .query()
+            .role(&PullRequestRole::Author)
+            .build();
+        assert_uri!(
+            uri,
+            format!("{}/inbox/pull-requests?role=AUTHOR", tests::base_uri())
+        );
+    }
+
+    #[test]
+    fn inbox_pull_requests_count_uri_works() {
+        let uri = builder().inbox().pull_requests().count().build();
+        assert_uri!(
+            uri,
+            format!("{}/inbox/pull-requests/count", tests::base_uri())
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::uri_builders::{
+    ApiProjectUriBuilder, ApiRepositoryUriBuilder, BuildResult, ResourceUriBuilder,
+    TerminalUriBuilder, UriBuilder,
+};
+
+/// The root of the default reviewers API, `rest/default-reviewers/1.0`.
+#[derive(Debug, Clone)]
+pub struct DefaultReviewersUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> DefaultReviewersUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn project(self, project: &'r str) -> ApiProjectUriBuilder<'r, Self> {
+        ApiProjectUriBuilder::new(self, project)
+    }
+}
+
+impl<'r> UriBuilder for DefaultReviewersUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+impl<'r> ApiProjectUriBuilder<'r, DefaultReviewersUriBuilder<'r>> {
+    terminal_resource_fn!(conditions);
+
+    pub fn condition(self, condition_id: u64) -> TerminalUriBuilder<Self> {
+        TerminalUriBuilder::new(self, format!("condition/{}", condition_id))
+    }
+}
+
+impl<'r> ApiRepositoryUriBuilder<'r, DefaultReviewersUriBuilder<'r>> {
+    terminal_resource_fn!(conditions);
+    terminal_resource_fn!(reviewers);
+
+    pub fn condition(self, condition_id: u64) -> TerminalUriBuilder<Self> {
+        TerminalUriBuilder::new(self, format!("condition/{}", condition_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+
+    fn base_uri() -> String {
+        format!(
+            "http://{}/rest/default-reviewers/1.0/projects/{}",
+            TEST_HOST, TEST_PROJECT
+        )
+    }
+
+    fn builder<'a>() -> ApiProjectUriBuilder<'a, DefaultReviewersUriBuilder<'a>> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .default_reviewers()
+            .project(TEST_PROJECT)
+    }
+
+    #[test]
+    fn project_conditions_uri_works() {
+        let uri = builder().conditions().build();
+        assert_uri!(uri, format!("{}/conditions", base_uri()));
+
+        let uri = builder().condition(3).build();
+        assert_uri!(uri, format!("{}/condition/3", base_uri()));
+    }
+
+    #[test]
+    fn repository_conditions_uri_works() {
+        let uri = builder().repository(TEST_REPO).conditions().build();
+        assert_uri!(
+            uri,
+            format!("{}/repos/{}/conditions", base_uri(), TEST_REPO)
+        );
+
+        let uri = builder().repository(TEST_REPO).condition(3).build();
+        assert_uri!(
+            uri,
+            format!("{}/repos/{}/condition/3", base_uri(), TEST_REPO)
+        );
+    }
+
+    #[test]
+    fn repository_reviewers_uri_works() {
+        let uri = builder().repository(TEST_REPO).reviewers().build();
+        assert_uri!(uri, format!("{}/repos/{}/reviewers", base_uri(), TEST_REPO));
+    }
+}
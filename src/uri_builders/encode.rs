@@ -0,0 +1,106 @@
+use crate::uri_builders::{BuildError, BuildResult};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+// Everything but the unreserved characters of RFC 3986 gets encoded.
+pub(crate) const COMPONENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Encodes a single path segment, e.g. a project key or a user slug.
+///
+/// Empty segments as well as `.` and `..` are rejected, since they would point to
+/// another resource than the intended one.
+pub(crate) fn encode_segment(segment: &str) -> BuildResult {
+    match segment {
+        "" => Err(BuildError::from("Path segments must not be empty")),
+        "." | ".." => Err(BuildError::new(format!(
+            "Path segment '{}' is not allowed",
+            segment
+        ))),
+        _ => Ok(utf8_percent_encode(segment, COMPONENT_ENCODE_SET).to_string()),
+    }
+}
+
+/// Encodes a file path, keeping `/` as the separator between its segments.
+pub(crate) fn encode_path(path: &str) -> BuildResult {
+    let segments = path
+        .split('/')
+        .map(encode_segment)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| BuildError::new(format!("Invalid path '{}': {}", path, e)))?;
+
+    Ok(segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use percent_encoding::percent_decode_str;
+    use proptest::prelude::*;
+
+    fn decode(encoded: &str) -> String {
+        percent_decode_str(encoded)
+            .decode_utf8()
+            .unwrap()
+            .into_owned()
+    }
+
+    fn segment() -> impl Strategy<Value = String> {
+        any::<String>().prop_filter("valid segment", |s| {
+            !s.is_empty() && s != "." && s != ".." && !s.contains('/')
+        })
+    }
+
+    #[test]
+    fn encode_segment_escapes_reserved_characters() {
+        assert_eq!(encode_segment("john@doe.com").unwrap(), "john%40doe.com");
+        assert_eq!(
+            encode_segment("a b#c?d/e%f").unwrap(),
+            "a%20b%23c%3Fd%2Fe%25f"
+        );
+        assert_eq!(encode_segment("feature-1_x~y").unwrap(), "feature-1_x~y");
+    }
+
+    #[test]
+    fn encode_segment_rejects_relative_segments() {
+        assert!(encode_segment("").is_err());
+        assert!(encode_segment(".").is_err());
+        assert!(encode_segment("..").is_err());
+        assert!(encode_segment("...").is_ok());
+    }
+
+    #[test]
+    fn encode_path_keeps_separators() {
+        assert_eq!(
+            encode_path("src/my file#1.rs").unwrap(),
+            "src/my%20file%231.rs"
+        );
+    }
+
+    #[test]
+    fn encode_path_rejects_relative_segments() {
+        assert!(encode_path("src/../secret").is_err());
+        assert!(encode_path("./src").is_err());
+        assert!(encode_path("src//main.rs").is_err());
+        assert!(encode_path("/src").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn encode_segment_round_trips(s in segment()) {
+            let encoded = encode_segment(&s).unwrap();
+            prop_assert!(!encoded.contains(['/', '?', '#']));
+            prop_assert_eq!(decode(&encoded), s);
+        }
+
+        #[test]
+        fn encode_path_round_trips(segments in prop::collection::vec(segment(), 1..5)) {
+            let path = segments.join("/");
+            let encoded = encode_path(&path).unwrap();
+            let decoded: Vec<_> = encoded.split('/').map(decode).collect();
+            prop_assert_eq!(decoded, segments);
+        }
+    }
+}
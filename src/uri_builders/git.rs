@@ -0,0 +1,100 @@
+use crate::uri_builders::encode::encode_path;
+use crate::uri_builders::{
+    ApiProjectUriBuilder, ApiRepositoryUriBuilder, BuildResult, ResourceUriBuilder, UriBuilder,
+};
+
+/// The root of the git API, `rest/git/1.0`.
+#[derive(Debug, Clone)]
+pub struct GitUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> GitUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn project(self, project: &'r str) -> ApiProjectUriBuilder<'r, Self> {
+        ApiProjectUriBuilder::new(self, project)
+    }
+}
+
+impl<'r> UriBuilder for GitUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+impl<'r> ApiRepositoryUriBuilder<'r, GitUriBuilder<'r>> {
+    pub fn tags(self) -> GitTagUriBuilder<'r> {
+        GitTagUriBuilder::new(self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitTagUriBuilder<'r> {
+    builder: ApiRepositoryUriBuilder<'r, GitUriBuilder<'r>>,
+}
+
+impl<'r> GitTagUriBuilder<'r> {
+    pub fn new(builder: ApiRepositoryUriBuilder<'r, GitUriBuilder<'r>>) -> Self {
+        Self { builder }
+    }
+
+    pub fn tag(self, name: &'r str) -> GitWithTagUriBuilder<'r> {
+        GitWithTagUriBuilder::new(self, name)
+    }
+}
+
+impl<'r> UriBuilder for GitTagUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/tags", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitWithTagUriBuilder<'r> {
+    builder: GitTagUriBuilder<'r>,
+    name: &'r str,
+}
+
+impl<'r> GitWithTagUriBuilder<'r> {
+    pub fn new(builder: GitTagUriBuilder<'r>, name: &'r str) -> Self {
+        Self { builder, name }
+    }
+}
+
+impl<'r> UriBuilder for GitWithTagUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/{}", self.builder.build()?, encode_path(self.name)?);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+
+    fn builder<'a>() -> GitTagUriBuilder<'a> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .git()
+            .project(TEST_PROJECT)
+            .repository(TEST_REPO)
+            .tags()
+    }
+
+    #[test]
+    fn git_tag_uri_works() {
+        let uri = builder().tag("release/1.0").build();
+        assert_uri!(
+            uri,
+            format!(
+                "http://{}/rest/git/1.0/projects/{}/repos/{}/tags/release/1.0",
+                TEST_HOST, TEST_PROJECT, TEST_REPO
+            )
+        );
+    }
+}
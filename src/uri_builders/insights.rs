@@ -0,0 +1,120 @@
+use crate::uri_builders::encode::encode_segment;
+use crate::uri_builders::{
+    ApiCommitUriBuilder, ApiProjectUriBuilder, ApiRepositoryUriBuilder, BuildResult,
+    ResourceUriBuilder, UriBuilder,
+};
+
+/// The root of the code insights API, `rest/insights/1.0`.
+#[derive(Debug, Clone)]
+pub struct InsightsUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> InsightsUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn project(self, project: &'r str) -> ApiProjectUriBuilder<'r, Self> {
+        ApiProjectUriBuilder::new(self, project)
+    }
+}
+
+impl<'r> UriBuilder for InsightsUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+impl<'r> ApiRepositoryUriBuilder<'r, InsightsUriBuilder<'r>> {
+    pub fn commit(self, commit_id: &'r str) -> ApiCommitUriBuilder<'r, InsightsUriBuilder<'r>> {
+        ApiCommitUriBuilder::new(self, commit_id)
+    }
+}
+
+impl<'r> ApiCommitUriBuilder<'r, InsightsUriBuilder<'r>> {
+    pub fn reports(self) -> ReportUriBuilder<'r> {
+        ReportUriBuilder::new(self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportUriBuilder<'r> {
+    builder: ApiCommitUriBuilder<'r, InsightsUriBuilder<'r>>,
+}
+
+impl<'r> ReportUriBuilder<'r> {
+    pub fn new(builder: ApiCommitUriBuilder<'r, InsightsUriBuilder<'r>>) -> Self {
+        Self { builder }
+    }
+
+    pub fn report(self, key: &'r str) -> WithReportUriBuilder<'r> {
+        WithReportUriBuilder::new(self, key)
+    }
+}
+
+impl<'r> UriBuilder for ReportUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/reports", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WithReportUriBuilder<'r> {
+    builder: ReportUriBuilder<'r>,
+    key: &'r str,
+}
+
+impl<'r> WithReportUriBuilder<'r> {
+    pub fn new(builder: ReportUriBuilder<'r>, key: &'r str) -> Self {
+        Self { builder, key }
+    }
+
+    terminal_resource_fn!(annotations);
+}
+
+impl<'r> UriBuilder for WithReportUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/{}", self.builder.build()?, encode_segment(self.key)?);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+
+    fn base_uri() -> String {
+        format!(
+            "http://{}/rest/insights/1.0/projects/{}/repos/{}/commits/abc123/reports",
+            TEST_HOST, TEST_PROJECT, TEST_REPO
+        )
+    }
+
+    fn builder<'a>() -> ReportUriBuilder<'a> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .insights()
+            .project(TEST_PROJECT)
+            .repository(TEST_REPO)
+            .commit("abc123")
+            .reports()
+    }
+
+    #[test]
+    fn report_uri_works() {
+        let uri = builder().build();
+        assert_uri!(uri, base_uri());
+
+        let uri = builder().report("sonar.scan").build();
+        assert_uri!(uri, format!("{}/sonar.scan", base_uri()));
+    }
+
+    #[test]
+    fn report_annotations_uri_works() {
+        let uri = builder().report("sonar.scan").annotations().build();
+        assert_uri!(uri, format!("{}/sonar.scan/annotations", base_uri()));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::uri_builders::{
+    ApiProjectUriBuilder, ApiRepositoryUriBuilder, BuildResult, ResourceUriBuilder, UriBuilder,
+};
+
+/// The root of the access keys API, `rest/keys/1.0`.
+#[derive(Debug, Clone)]
+pub struct KeysUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> KeysUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn project(self, project: &'r str) -> ApiProjectUriBuilder<'r, Self> {
+        ApiProjectUriBuilder::new(self, project)
+    }
+}
+
+impl<'r> UriBuilder for KeysUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+impl<'r> ApiProjectUriBuilder<'r, KeysUriBuilder<'r>> {
+    terminal_resource_fn!(ssh);
+}
+
+impl<'r> ApiRepositoryUriBuilder<'r, KeysUriBuilder<'r>> {
+    terminal_resource_fn!(ssh);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+
+    fn base_uri() -> String {
+        format!(
+            "http://{}/rest/keys/1.0/projects/{}",
+            TEST_HOST, TEST_PROJECT
+        )
+    }
+
+    fn builder<'a>() -> ApiProjectUriBuilder<'a, KeysUriBuilder<'a>> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .keys()
+            .project(TEST_PROJECT)
+    }
+
+    #[test]
+    fn project_access_keys_uri_works() {
+        let uri = builder().ssh().build();
+        assert_uri!(uri, format!("{}/ssh", base_uri()));
+    }
+
+    #[test]
+    fn repository_access_keys_uri_works() {
+        let uri = builder().repository(TEST_REPO).ssh().build();
+        assert_uri!(uri, format!("{}/repos/{}/ssh", base_uri(), TEST_REPO));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::uri_builders::encode::encode_segment;
 use crate::uri_builders::{BuildResult, ResourceUriBuilder, TerminalUriBuilder, UriBuilder};
 
 #[derive(Debug, Clone)]
@@ -44,7 +45,11 @@ impl<'r> WithLoggerUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithLoggerUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/logger/{}", self.builder.build()?, self.logger);
+        let uri = format!(
+            "{}/logger/{}",
+            self.builder.build()?,
+            encode_segment(self.logger)?
+        );
         Ok(uri)
     }
 }
@@ -1,7 +1,5 @@
 use std::fmt;
 
-const REST_API_URI: &str = "rest/api/1.0";
-
 #[derive(Debug)]
 pub struct BuildError {
     msg: String,
@@ -41,15 +39,22 @@ type BuildResult = Result<String, BuildError>;
 
 pub trait UriBuilder {
     fn build(&self) -> BuildResult;
+
+    fn query(self) -> QueryUriBuilder<Self>
+    where
+        Self: Sized,
+    {
+        QueryUriBuilder::new(self)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TerminalUriBuilder<B> {
     builder: B,
     resource: String,
 }
 
-impl<'r, B> TerminalUriBuilder<B>
+impl<B> TerminalUriBuilder<B>
 where
     B: UriBuilder,
 {
@@ -80,11 +85,12 @@ macro_rules! terminal_resource_fn {
 #[cfg(test)]
 #[macro_use]
 mod tests {
-    use crate::uri_builders::{BuildResult, UriBuilder, REST_API_URI};
+    use crate::uri_builders::{BuildResult, UriBuilder};
 
-    pub const TEST_HOST: &'static str = "stash.test.com";
-    pub const TEST_PROJECT: &'static str = "RRJ";
-    pub const TEST_REPO: &'static str = "REPO";
+    pub const REST_API_URI: &str = "rest/api/1.0";
+    pub const TEST_HOST: &str = "stash.test.com";
+    pub const TEST_PROJECT: &str = "RRJ";
+    pub const TEST_REPO: &str = "REPO";
 
     pub fn base_uri() -> String {
         format!("http://{}/{}", TEST_HOST, REST_API_URI)
@@ -106,33 +112,63 @@ mod tests {
     }
 }
 
+mod access_tokens;
 mod admin;
+mod api;
 mod branch;
+mod branch_utils;
 mod browse;
+mod build_status;
 mod commit;
+mod dashboard;
+mod default_reviewers;
 mod diff;
+mod encode;
 mod file;
+mod git;
+mod insights;
+mod keys;
 mod log;
 mod path;
 mod permission;
 mod project;
 mod pull_request;
+mod query;
 mod repository;
 mod resource;
+mod search;
+mod ssh;
+mod tag;
+mod task;
 mod user;
 
+pub use access_tokens::*;
 pub use admin::*;
+pub use api::*;
 pub use branch::*;
+pub use branch_utils::*;
 pub use browse::*;
+pub use build_status::*;
 pub use commit::*;
+pub use dashboard::*;
+pub use default_reviewers::*;
 pub use diff::*;
 pub use file::*;
+pub use git::*;
+pub use insights::*;
+pub use keys::*;
 pub use log::*;
+pub use path::*;
 pub use permission::*;
 pub use project::*;
 pub use pull_request::*;
+pub use query::*;
 pub use repository::*;
 pub use resource::*;
+pub use search::*;
+pub use ssh::*;
+pub use tag::*;
+pub use task::*;
 
 use std::error::Error;
 use std::fmt::Formatter;
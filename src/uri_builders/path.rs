@@ -1,4 +1,5 @@
-use crate::uri_builders::{BuildResult, TerminalUriBuilder, UriBuilder};
+use crate::uri_builders::encode::{encode_path, encode_segment};
+use crate::uri_builders::{BuildResult, UriBuilder};
 
 #[derive(Debug, Clone)]
 pub struct PathUriBuilder<'r, B> {
@@ -14,8 +15,8 @@ where
         Self { builder, path }
     }
 
-    pub fn path(self, path: &str) -> TerminalUriBuilder<Self> {
-        TerminalUriBuilder::new(self, path.to_string())
+    pub fn path(self, path: &str) -> WithPathUriBuilder<Self> {
+        WithPathUriBuilder::new(self, path)
     }
 }
 
@@ -24,7 +25,34 @@ where
     B: UriBuilder,
 {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.path);
+        let uri = format!("{}/{}", self.builder.build()?, encode_segment(self.path)?);
+        Ok(uri)
+    }
+}
+
+/// A file path below a path resource, e.g. `browse/src/main.rs`.
+#[derive(Debug, Clone)]
+pub struct WithPathUriBuilder<B> {
+    builder: B,
+    path: String,
+}
+
+impl<B> WithPathUriBuilder<B>
+where
+    B: UriBuilder,
+{
+    pub fn new(builder: B, path: &str) -> Self {
+        let path = path.to_string();
+        Self { builder, path }
+    }
+}
+
+impl<B> UriBuilder for WithPathUriBuilder<B>
+where
+    B: UriBuilder,
+{
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/{}", self.builder.build()?, encode_path(&self.path)?);
         Ok(uri)
     }
 }
@@ -49,4 +77,16 @@ mod tests {
         let uri = builder().path("home/test").build();
         assert_uri!(uri, "/test/home/test");
     }
+
+    #[test]
+    fn with_path_uri_encodes_segments() {
+        let uri = builder().path("home/my test/#1.txt").build();
+        assert_uri!(uri, "/test/home/my%20test/%231.txt");
+    }
+
+    #[test]
+    fn with_path_uri_rejects_parent_segments() {
+        let uri = builder().path("home/../etc/passwd").build();
+        assert!(uri.is_err());
+    }
 }
@@ -1,6 +1,7 @@
+use crate::uri_builders::encode::encode_segment;
 use crate::uri_builders::{
-    BuildResult, GroupPermissionUriBuilder, PermissionUriBuilder, RepositoryUriBuilder,
-    ResourceUriBuilder, UriBuilder, UserPermissionUriBuilder,
+    BuildResult, GroupPermissionUriBuilder, PermissionUriBuilder, QueryUriBuilder,
+    RepositoryUriBuilder, ResourceUriBuilder, UriBuilder, UserPermissionUriBuilder,
 };
 
 #[derive(Debug, Clone)]
@@ -25,6 +26,16 @@ impl<'r> UriBuilder for ProjectUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<ProjectUriBuilder<'r>> {
+    pub fn name(self, name: &str) -> Self {
+        self.param("name", name)
+    }
+
+    pub fn permission(self, permission: &str) -> Self {
+        self.param("permission", permission)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WithProjectUriBuilder<'r> {
     builder: ProjectUriBuilder<'r>,
@@ -51,7 +62,11 @@ impl<'r> WithProjectUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithProjectUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.project);
+        let uri = format!(
+            "{}/{}",
+            self.builder.build()?,
+            encode_segment(self.project)?
+        );
         Ok(uri)
     }
 }
@@ -122,7 +137,11 @@ impl<'r> WithProjectPermissionUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithProjectPermissionUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.permission);
+        let uri = format!(
+            "{}/{}",
+            self.builder.build()?,
+            encode_segment(self.permission)?
+        );
         Ok(uri)
     }
 }
@@ -152,6 +171,24 @@ mod tests {
         assert_uri!(uri, format!("{}/projects", tests::base_uri()));
     }
 
+    #[test]
+    fn project_query_uri_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .query()
+            .name("My Project")
+            .permission("PROJECT_READ")
+            .build();
+        assert_uri!(
+            uri,
+            format!(
+                "{}/projects?name=My%20Project&permission=PROJECT_READ",
+                tests::base_uri()
+            )
+        );
+    }
+
     #[test]
     fn with_project_uri_works() {
         let uri = builder().build();
@@ -1,4 +1,11 @@
-use crate::uri_builders::{BuildResult, DiffUriBuilder, UriBuilder, WithRepositoryUriBuilder};
+use crate::models::get::{
+    PullRequestActivityFromType, PullRequestDirection, PullRequestOrder, PullRequestState,
+};
+use crate::uri_builders::encode::encode_segment;
+use crate::uri_builders::{
+    BuildResult, DiffUriBuilder, QueryUriBuilder, TerminalUriBuilder, UriBuilder,
+    WithRepositoryUriBuilder,
+};
 
 #[derive(Debug, Clone)]
 pub struct PullRequestUriBuilder<'r> {
@@ -22,6 +29,36 @@ impl<'r> UriBuilder for PullRequestUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<PullRequestUriBuilder<'r>> {
+    pub fn state(self, state: &PullRequestState) -> Self {
+        self.param("state", state.as_str())
+    }
+
+    pub fn direction(self, direction: PullRequestDirection) -> Self {
+        self.param("direction", direction.as_str())
+    }
+
+    pub fn at(self, branch: &str) -> Self {
+        self.param("at", branch)
+    }
+
+    pub fn order(self, order: PullRequestOrder) -> Self {
+        self.param("order", order.as_str())
+    }
+
+    pub fn filter_text(self, filter_text: &str) -> Self {
+        self.param("filterText", filter_text)
+    }
+
+    pub fn with_attributes(self, with_attributes: bool) -> Self {
+        self.param("withAttributes", with_attributes)
+    }
+
+    pub fn with_properties(self, with_properties: bool) -> Self {
+        self.param("withProperties", with_properties)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WithPullRequestUriBuilder<'r> {
     builder: PullRequestUriBuilder<'r>,
@@ -45,15 +82,21 @@ impl<'r> WithPullRequestUriBuilder<'r> {
         PullRequestTasksUriBuilder::new(self)
     }
 
-    terminal_resource_fn!(activities);
     terminal_resource_fn!(decline);
     terminal_resource_fn!(merge);
     terminal_resource_fn!(reopen);
     terminal_resource_fn!(approve);
     terminal_resource_fn!(changes);
     terminal_resource_fn!(commits);
-    terminal_resource_fn!(participants);
     terminal_resource_fn!(watch);
+
+    pub fn participants(self) -> PullRequestParticipantsUriBuilder<'r> {
+        PullRequestParticipantsUriBuilder::new(self)
+    }
+
+    pub fn activities(self) -> PullRequestActivitiesUriBuilder<'r> {
+        PullRequestActivitiesUriBuilder::new(self)
+    }
 }
 
 impl<'r> UriBuilder for WithPullRequestUriBuilder<'r> {
@@ -63,6 +106,85 @@ impl<'r> UriBuilder for WithPullRequestUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<TerminalUriBuilder<WithPullRequestUriBuilder<'r>>> {
+    pub fn version(self, version: u32) -> Self {
+        self.param("version", version)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PullRequestActivitiesUriBuilder<'r> {
+    builder: WithPullRequestUriBuilder<'r>,
+}
+
+impl<'r> PullRequestActivitiesUriBuilder<'r> {
+    pub fn new(builder: WithPullRequestUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+}
+
+impl<'r> UriBuilder for PullRequestActivitiesUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/activities", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+impl<'r> QueryUriBuilder<PullRequestActivitiesUriBuilder<'r>> {
+    pub fn from_id(self, from_id: u64) -> Self {
+        self.param("fromId", from_id)
+    }
+
+    pub fn from_type(self, from_type: PullRequestActivityFromType) -> Self {
+        self.param("fromType", from_type.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PullRequestParticipantsUriBuilder<'r> {
+    builder: WithPullRequestUriBuilder<'r>,
+}
+
+impl<'r> PullRequestParticipantsUriBuilder<'r> {
+    pub fn new(builder: WithPullRequestUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn participant(self, user_slug: &'r str) -> WithPullRequestParticipantUriBuilder<'r> {
+        WithPullRequestParticipantUriBuilder::new(self, user_slug)
+    }
+}
+
+impl<'r> UriBuilder for PullRequestParticipantsUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/participants", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WithPullRequestParticipantUriBuilder<'r> {
+    builder: PullRequestParticipantsUriBuilder<'r>,
+    user_slug: &'r str,
+}
+
+impl<'r> WithPullRequestParticipantUriBuilder<'r> {
+    pub fn new(builder: PullRequestParticipantsUriBuilder<'r>, user_slug: &'r str) -> Self {
+        Self { builder, user_slug }
+    }
+}
+
+impl<'r> UriBuilder for WithPullRequestParticipantUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!(
+            "{}/{}",
+            self.builder.build()?,
+            encode_segment(self.user_slug)?
+        );
+        Ok(uri)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PullRequestCommentUriBuilder<'r> {
     builder: WithPullRequestUriBuilder<'r>,
@@ -107,6 +229,12 @@ impl<'r> UriBuilder for WithPullRequestCommentUriBuilder<'r> {
     }
 }
 
+impl<'r> QueryUriBuilder<WithPullRequestCommentUriBuilder<'r>> {
+    pub fn version(self, version: u32) -> Self {
+        self.param("version", version)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PullRequestTasksUriBuilder<'r> {
     builder: WithPullRequestUriBuilder<'r>,
@@ -167,6 +295,33 @@ mod tests {
         assert_uri!(uri, base_uri());
     }
 
+    #[test]
+    fn pull_request_query_uri_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .project(TEST_PROJECT)
+            .repos()
+            .repository(TEST_REPO)
+            .pull_requests()
+            .query()
+            .state(&PullRequestState::Open)
+            .direction(PullRequestDirection::Incoming)
+            .at("refs/heads/master")
+            .order(PullRequestOrder::Newest)
+            .with_attributes(false)
+            .with_properties(true)
+            .build();
+
+        assert_uri!(
+            uri,
+            format!(
+                "{}?state=OPEN&direction=INCOMING&at=refs%2Fheads%2Fmaster&order=NEWEST&withAttributes=false&withProperties=true",
+                base_uri()
+            )
+        );
+    }
+
     #[test]
     fn with_pull_request_uri_works() {
         let uri = builder().build();
@@ -179,6 +334,20 @@ mod tests {
         assert_uri!(uri, format!("{}/1/activities", base_uri()));
     }
 
+    #[test]
+    fn with_pull_request_activities_from_uri_works() {
+        let uri = builder()
+            .activities()
+            .query()
+            .from_id(12)
+            .from_type(PullRequestActivityFromType::Comment)
+            .build();
+        assert_uri!(
+            uri,
+            format!("{}/1/activities?fromId=12&fromType=COMMENT", base_uri())
+        );
+    }
+
     #[test]
     fn with_pull_request_decline_uri_works() {
         let uri = builder().decline().build();
@@ -191,6 +360,12 @@ mod tests {
         assert_uri!(uri, format!("{}/1/merge", base_uri()));
     }
 
+    #[test]
+    fn with_pull_request_merge_version_uri_works() {
+        let uri = builder().merge().query().version(3).build();
+        assert_uri!(uri, format!("{}/1/merge?version=3", base_uri()));
+    }
+
     #[test]
     fn with_pull_request_reopen_uri_works() {
         let uri = builder().reopen().build();
@@ -221,6 +396,12 @@ mod tests {
         assert_uri!(uri, format!("{}/1/comments/1", base_uri()));
     }
 
+    #[test]
+    fn with_comment_version_uri_works() {
+        let uri = builder().comments().comment(1).query().version(2).build();
+        assert_uri!(uri, format!("{}/1/comments/1?version=2", base_uri()));
+    }
+
     #[test]
     fn with_pull_request_commits_uri_works() {
         let uri = builder().commits().build();
@@ -239,6 +420,12 @@ mod tests {
         assert_uri!(uri, format!("{}/1/participants", base_uri()));
     }
 
+    #[test]
+    fn with_pull_request_participant_uri_works() {
+        let uri = builder().participants().participant("john@doe").build();
+        assert_uri!(uri, format!("{}/1/participants/john%40doe", base_uri()));
+    }
+
     #[test]
     fn with_pull_request_tasks_uri_works() {
         let uri = builder().tasks().build();
@@ -0,0 +1,115 @@
+use crate::uri_builders::encode::COMPONENT_ENCODE_SET;
+use crate::uri_builders::{BuildResult, UriBuilder};
+use percent_encoding::utf8_percent_encode;
+
+#[derive(Debug, Clone)]
+pub struct QueryUriBuilder<B> {
+    builder: B,
+    params: Vec<(String, String)>,
+}
+
+impl<B> QueryUriBuilder<B>
+where
+    B: UriBuilder,
+{
+    pub fn new(builder: B) -> Self {
+        Self {
+            builder,
+            params: Vec::new(),
+        }
+    }
+
+    /// Sets a query parameter, replacing any previous value of it.
+    pub fn param<V: ToString>(mut self, key: &str, value: V) -> Self {
+        self.params.retain(|(k, _)| k != key);
+        self.params.push((key.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Adds a query parameter, keeping any previous values of it.
+    pub fn append_param<V: ToString>(mut self, key: &str, value: V) -> Self {
+        self.params.push((key.to_owned(), value.to_string()));
+        self
+    }
+
+    pub fn limit(self, limit: u32) -> Self {
+        self.param("limit", limit)
+    }
+
+    pub fn start(self, start: u32) -> Self {
+        self.param("start", start)
+    }
+}
+
+impl<B> UriBuilder for QueryUriBuilder<B>
+where
+    B: UriBuilder,
+{
+    fn build(&self) -> BuildResult {
+        let mut uri = self.builder.build()?;
+
+        for (key, value) in &self.params {
+            uri.push(if uri.contains('?') { '&' } else { '?' });
+            uri.push_str(&utf8_percent_encode(key, COMPONENT_ENCODE_SET).to_string());
+            uri.push('=');
+            uri.push_str(&utf8_percent_encode(value, COMPONENT_ENCODE_SET).to_string());
+        }
+
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::EmptyUriBuilder;
+
+    fn builder() -> QueryUriBuilder<EmptyUriBuilder> {
+        EmptyUriBuilder.query()
+    }
+
+    #[test]
+    fn query_without_params_works() {
+        let uri = builder().build();
+        assert_uri!(uri, "");
+    }
+
+    #[test]
+    fn query_params_work() {
+        let uri = builder().param("state", "OPEN").limit(25).start(50).build();
+        assert_uri!(uri, "?state=OPEN&limit=25&start=50");
+    }
+
+    #[test]
+    fn query_params_are_replaced() {
+        let uri = builder().start(0).limit(25).start(50).build();
+        assert_uri!(uri, "?limit=25&start=50");
+    }
+
+    #[test]
+    fn query_params_are_appended() {
+        let uri = builder()
+            .append_param("user", "a")
+            .append_param("user", "b")
+            .build();
+        assert_uri!(uri, "?user=a&user=b");
+    }
+
+    #[test]
+    fn query_params_are_percent_encoded() {
+        let uri = builder()
+            .param("at", "refs/heads/feature/a&b")
+            .param("filterText", "50% off #1 é")
+            .build();
+        assert_uri!(
+            uri,
+            "?at=refs%2Fheads%2Ffeature%2Fa%26b&filterText=50%25%20off%20%231%20%C3%A9"
+        );
+    }
+
+    #[test]
+    fn nested_queries_are_merged() {
+        let uri = builder().param("a", 1).query().param("b", 2).build();
+        assert_uri!(uri, "?a=1&b=2");
+    }
+}
@@ -1,6 +1,8 @@
+use crate::uri_builders::encode::encode_segment;
 use crate::uri_builders::{
     BranchUriBuilder, BrowseUriBuilder, BuildResult, CommitUriBuilder, DiffUriBuilder,
-    FileUriBuilder, PermissionUriBuilder, PullRequestUriBuilder, UriBuilder, WithProjectUriBuilder,
+    FileUriBuilder, PermissionUriBuilder, PullRequestUriBuilder, TagUriBuilder, UriBuilder,
+    WithProjectUriBuilder,
 };
 
 #[derive(Debug, Clone)]
@@ -40,7 +42,10 @@ impl<'r> WithRepositoryUriBuilder<'r> {
     terminal_resource_fn!(recreate);
     terminal_resource_fn!(related);
     terminal_resource_fn!(changes);
-    terminal_resource_fn!(tags);
+
+    pub fn tags(self) -> TagUriBuilder<'r> {
+        TagUriBuilder::new(self)
+    }
 
     pub fn branches(self) -> BranchUriBuilder<'r> {
         BranchUriBuilder::new(self)
@@ -81,7 +86,7 @@ impl<'r> WithRepositoryUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithRepositoryUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.repo);
+        let uri = format!("{}/{}", self.builder.build()?, encode_segment(self.repo)?);
         Ok(uri)
     }
 }
@@ -172,7 +177,7 @@ impl<'r> WithHookUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithHookUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.hook);
+        let uri = format!("{}/{}", self.builder.build()?, encode_segment(self.hook)?);
         Ok(uri)
     }
 }
@@ -182,6 +187,8 @@ mod tests {
     use super::*;
     use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
     use crate::uri_builders::ResourceUriBuilder;
+    use percent_encoding::percent_decode_str;
+    use proptest::prelude::*;
 
     fn base_uri() -> String {
         format!(
@@ -297,4 +304,74 @@ mod tests {
             .build();
         assert_uri!(uri, format_repo_uri("settings/hooks/test-hook/settings"));
     }
+
+    #[test]
+    fn repository_uri_encodes_segments() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .project("~john@doe.com")
+            .repos()
+            .repository("my repo")
+            .browse()
+            .path("src/100%.rs")
+            .build();
+        assert_uri!(
+            uri,
+            format!(
+                "{}/projects/~john%40doe.com/repos/my%20repo/browse/src/100%25.rs",
+                crate::uri_builders::tests::base_uri()
+            )
+        );
+    }
+
+    #[test]
+    fn repository_uri_rejects_parent_segments() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .project(TEST_PROJECT)
+            .repos()
+            .repository("..")
+            .build();
+        assert!(uri.is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn repository_uri_round_trips(
+            project in "[^/]+",
+            repo in "[^/]+",
+            path in prop::collection::vec("[^/]+", 1..4),
+        ) {
+            prop_assume!(![&project, &repo].iter().any(|s| *s == "." || *s == ".."));
+            prop_assume!(path.iter().all(|s| s != "." && s != ".."));
+
+            let path = path.join("/");
+            let uri = ResourceUriBuilder::default()
+                .host(TEST_HOST)
+                .projects()
+                .project(&project)
+                .repos()
+                .repository(&repo)
+                .files()
+                .path(&path)
+                .build()
+                .unwrap();
+
+            let prefix = format!("{}/projects/", crate::uri_builders::tests::base_uri());
+            let segments: Vec<_> = uri
+                .strip_prefix(&prefix)
+                .unwrap()
+                .split('/')
+                .map(|s| percent_decode_str(s).decode_utf8().unwrap().into_owned())
+                .collect();
+
+            prop_assert_eq!(&segments[0], &project);
+            prop_assert_eq!(&segments[1], "repos");
+            prop_assert_eq!(&segments[2], &repo);
+            prop_assert_eq!(&segments[3], "files");
+            prop_assert_eq!(segments[4..].join("/"), path);
+        }
+    }
 }
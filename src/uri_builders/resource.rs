@@ -1,13 +1,19 @@
 use crate::uri_builders::{
-    AdminUriBuilder, BuildResult, LogUriBuilder, ProjectUriBuilder, UriBuilder, UserUriBuilder,
-    REST_API_URI,
+    AccessTokensUriBuilder, AdminUriBuilder, BranchUtilsUriBuilder, BuildResult,
+    BuildStatusUriBuilder, DashboardUriBuilder, DefaultReviewersUriBuilder, GitUriBuilder,
+    InboxUriBuilder, InsightsUriBuilder, KeysUriBuilder, LogUriBuilder, ProjectUriBuilder, RestApi,
+    SearchUriBuilder, SshUriBuilder, TaskUriBuilder, UriBuilder, UserUriBuilder,
 };
-use crate::Scheme;
+use crate::{BaseUrl, Scheme};
 
 #[derive(Debug, Clone)]
 pub struct ResourceUriBuilder<'r> {
     scheme: Scheme,
     host: Option<&'r str>,
+    port: Option<u16>,
+    context_path: &'r str,
+    api: RestApi,
+    version: Option<&'r str>,
 }
 
 impl<'r> Default for ResourceUriBuilder<'r> {
@@ -15,6 +21,10 @@ impl<'r> Default for ResourceUriBuilder<'r> {
         Self {
             scheme: Scheme::HTTP,
             host: None,
+            port: None,
+            context_path: "",
+            api: RestApi::Core,
+            version: None,
         }
     }
 }
@@ -30,6 +40,34 @@ impl<'r> ResourceUriBuilder<'r> {
         self
     }
 
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn context_path(mut self, context_path: &'r str) -> Self {
+        self.context_path = context_path.trim_matches('/');
+        self
+    }
+
+    pub fn base_url(self, base_url: &'r BaseUrl) -> Self {
+        let builder = self
+            .scheme(base_url.scheme())
+            .host(base_url.host())
+            .context_path(base_url.context_path());
+
+        match base_url.port() {
+            Some(port) => builder.port(port),
+            None => builder,
+        }
+    }
+
+    /// Overrides the default version of the REST API family, e.g. `2.0` for `rest/api/2.0`.
+    pub fn version(mut self, version: &'r str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
     pub fn admin(self) -> AdminUriBuilder<'r> {
         AdminUriBuilder::new(self)
     }
@@ -45,24 +83,83 @@ impl<'r> ResourceUriBuilder<'r> {
     pub fn logs(self) -> LogUriBuilder<'r> {
         LogUriBuilder::new(self)
     }
+
+    pub fn dashboard(self) -> DashboardUriBuilder<'r> {
+        DashboardUriBuilder::new(self)
+    }
+
+    pub fn inbox(self) -> InboxUriBuilder<'r> {
+        InboxUriBuilder::new(self)
+    }
+
+    pub fn tasks(self) -> TaskUriBuilder<'r> {
+        TaskUriBuilder::new(self)
+    }
+
+    pub fn access_tokens(self) -> AccessTokensUriBuilder<'r> {
+        AccessTokensUriBuilder::new(self.api(RestApi::AccessTokens))
+    }
+
+    pub fn branch_utils(self) -> BranchUtilsUriBuilder<'r> {
+        BranchUtilsUriBuilder::new(self.api(RestApi::BranchUtils))
+    }
+
+    pub fn build_status(self) -> BuildStatusUriBuilder<'r> {
+        BuildStatusUriBuilder::new(self.api(RestApi::BuildStatus))
+    }
+
+    pub fn default_reviewers(self) -> DefaultReviewersUriBuilder<'r> {
+        DefaultReviewersUriBuilder::new(self.api(RestApi::DefaultReviewers))
+    }
+
+    pub fn git(self) -> GitUriBuilder<'r> {
+        GitUriBuilder::new(self.api(RestApi::Git))
+    }
+
+    pub fn insights(self) -> InsightsUriBuilder<'r> {
+        InsightsUriBuilder::new(self.api(RestApi::Insights))
+    }
+
+    pub fn keys(self) -> KeysUriBuilder<'r> {
+        KeysUriBuilder::new(self.api(RestApi::Keys))
+    }
+
+    pub fn search(self) -> SearchUriBuilder<'r> {
+        SearchUriBuilder::new(self.api(RestApi::Search))
+    }
+
+    pub fn ssh(self) -> SshUriBuilder<'r> {
+        SshUriBuilder::new(self.api(RestApi::Ssh))
+    }
+
+    fn api(mut self, api: RestApi) -> Self {
+        self.api = api;
+        self
+    }
 }
 
 impl<'r> UriBuilder for ResourceUriBuilder<'r> {
     fn build(&self) -> BuildResult {
         let host = self.host.ok_or("host must be initialized")?;
-        let scheme = match &self.scheme {
-            Scheme::HTTP => "http",
-            Scheme::HTTPS => "https",
-        };
+        let mut uri = format!("{}://{}", self.scheme.as_str(), host);
 
-        Ok(format!("{}://{}/{}", scheme, host, REST_API_URI))
+        if let Some(port) = self.port {
+            uri.push_str(&format!(":{}", port));
+        }
+        if !self.context_path.is_empty() {
+            uri.push('/');
+            uri.push_str(self.context_path);
+        }
+
+        let version = self.version.unwrap_or_else(|| self.api.default_version());
+        Ok(format!("{}/rest/{}/{}", uri, self.api.name(), version))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::uri_builders::tests::{base_uri, TEST_HOST};
+    use crate::uri_builders::tests::{base_uri, REST_API_URI, TEST_HOST};
 
     #[test]
     fn resource_uri_builder_requires_host() {
@@ -94,4 +191,45 @@ mod tests {
         let uri = builder.build();
         assert_uri!(uri, base_uri());
     }
+
+    #[test]
+    fn resource_uri_with_port_and_context_path_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .port(7990)
+            .context_path("/bitbucket/")
+            .build();
+        assert_uri!(
+            uri,
+            format!("http://{}:7990/bitbucket/{}", TEST_HOST, REST_API_URI)
+        );
+    }
+
+    #[test]
+    fn resource_uri_with_base_url_works() {
+        let base_url = BaseUrl::parse("https://git.corp.example/bitbucket").unwrap();
+        let uri = ResourceUriBuilder::default().base_url(&base_url).build();
+        assert_uri!(
+            uri,
+            format!("https://git.corp.example/bitbucket/{}", REST_API_URI)
+        );
+    }
+
+    #[test]
+    fn resource_uri_with_version_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .version("latest")
+            .build();
+        assert_uri!(uri, format!("http://{}/rest/api/latest", TEST_HOST));
+    }
+
+    #[test]
+    fn resource_uri_of_api_family_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .branch_utils()
+            .build();
+        assert_uri!(uri, format!("http://{}/rest/branch-utils/1.0", TEST_HOST));
+    }
 }
@@ -0,0 +1,38 @@
+use crate::uri_builders::{BuildResult, ResourceUriBuilder, UriBuilder};
+
+/// The code search endpoint, `rest/search/latest/search`.
+#[derive(Debug, Clone)]
+pub struct SearchUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> SearchUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+}
+
+impl<'r> UriBuilder for SearchUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/search", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::TEST_HOST;
+
+    #[test]
+    fn search_uri_works() {
+        let uri = ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .search()
+            .build();
+        assert_uri!(
+            uri,
+            format!("http://{}/rest/search/latest/search", TEST_HOST)
+        );
+    }
+}
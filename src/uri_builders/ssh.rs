@@ -0,0 +1,79 @@
+use crate::uri_builders::{
+    BuildResult, QueryUriBuilder, ResourceUriBuilder, TerminalUriBuilder, UriBuilder,
+};
+
+/// The root of the SSH keys API of the users, `rest/ssh/1.0`.
+#[derive(Debug, Clone)]
+pub struct SshUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> SshUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn keys(self) -> SshKeyUriBuilder<'r> {
+        SshKeyUriBuilder::new(self)
+    }
+}
+
+impl<'r> UriBuilder for SshUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        self.builder.build()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SshKeyUriBuilder<'r> {
+    builder: SshUriBuilder<'r>,
+}
+
+impl<'r> SshKeyUriBuilder<'r> {
+    pub fn new(builder: SshUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn key(self, key_id: u64) -> TerminalUriBuilder<Self> {
+        TerminalUriBuilder::new(self, key_id.to_string())
+    }
+}
+
+impl<'r> UriBuilder for SshKeyUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/keys", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+impl<'r> QueryUriBuilder<SshKeyUriBuilder<'r>> {
+    pub fn user(self, user: &str) -> Self {
+        self.param("user", user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::TEST_HOST;
+
+    fn base_uri() -> String {
+        format!("http://{}/rest/ssh/1.0/keys", TEST_HOST)
+    }
+
+    fn builder<'a>() -> SshKeyUriBuilder<'a> {
+        ResourceUriBuilder::default().host(TEST_HOST).ssh().keys()
+    }
+
+    #[test]
+    fn ssh_keys_uri_works() {
+        let uri = builder().query().user("jdoe").build();
+        assert_uri!(uri, format!("{}?user=jdoe", base_uri()));
+    }
+
+    #[test]
+    fn ssh_key_uri_works() {
+        let uri = builder().key(7).build();
+        assert_uri!(uri, format!("{}/7", base_uri()));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::models::get::TagOrder;
+use crate::uri_builders::encode::encode_path;
+use crate::uri_builders::{BuildResult, QueryUriBuilder, UriBuilder, WithRepositoryUriBuilder};
+
+#[derive(Debug, Clone)]
+pub struct TagUriBuilder<'r> {
+    builder: WithRepositoryUriBuilder<'r>,
+}
+
+impl<'r> TagUriBuilder<'r> {
+    pub fn new(builder: WithRepositoryUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn tag(self, name: &'r str) -> WithTagUriBuilder<'r> {
+        WithTagUriBuilder::new(self, name)
+    }
+}
+
+impl<'r> UriBuilder for TagUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/tags", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+impl<'r> QueryUriBuilder<TagUriBuilder<'r>> {
+    pub fn filter_text(self, filter_text: &str) -> Self {
+        self.param("filterText", filter_text)
+    }
+
+    pub fn order_by(self, order_by: &TagOrder) -> Self {
+        self.param("orderBy", order_by.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WithTagUriBuilder<'r> {
+    builder: TagUriBuilder<'r>,
+    name: &'r str,
+}
+
+impl<'r> WithTagUriBuilder<'r> {
+    pub fn new(builder: TagUriBuilder<'r>, name: &'r str) -> Self {
+        Self { builder, name }
+    }
+}
+
+impl<'r> UriBuilder for WithTagUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/{}", self.builder.build()?, encode_path(self.name)?);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{TEST_HOST, TEST_PROJECT, TEST_REPO};
+    use crate::uri_builders::ResourceUriBuilder;
+
+    fn base_uri() -> String {
+        format!(
+            "{}/projects/{}/repos/{}/tags",
+            crate::uri_builders::tests::base_uri(),
+            TEST_PROJECT,
+            TEST_REPO
+        )
+    }
+
+    fn builder<'a>() -> TagUriBuilder<'a> {
+        ResourceUriBuilder::default()
+            .host(TEST_HOST)
+            .projects()
+            .project(TEST_PROJECT)
+            .repos()
+            .repository(TEST_REPO)
+            .tags()
+    }
+
+    #[test]
+    fn tag_uri_works() {
+        let uri = builder().build();
+        assert_uri!(uri, base_uri());
+    }
+
+    #[test]
+    fn tag_query_uri_works() {
+        let uri = builder()
+            .query()
+            .filter_text("v1.")
+            .order_by(&TagOrder::Alphabetical)
+            .build();
+        assert_uri!(
+            uri,
+            format!("{}?filterText=v1.&orderBy=ALPHABETICAL", base_uri())
+        );
+    }
+
+    #[test]
+    fn with_tag_uri_encodes_name() {
+        let uri = builder().tag("release/1.0 rc").build();
+        assert_uri!(uri, format!("{}/release/1.0%20rc", base_uri()));
+    }
+}
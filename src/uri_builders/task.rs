@@ -0,0 +1,64 @@
+use crate::uri_builders::{BuildResult, ResourceUriBuilder, UriBuilder};
+
+#[derive(Debug, Clone)]
+pub struct TaskUriBuilder<'r> {
+    builder: ResourceUriBuilder<'r>,
+}
+
+impl<'r> TaskUriBuilder<'r> {
+    pub fn new(builder: ResourceUriBuilder<'r>) -> Self {
+        Self { builder }
+    }
+
+    pub fn task(self, task_id: u64) -> WithTaskUriBuilder<'r> {
+        WithTaskUriBuilder::new(self, task_id)
+    }
+}
+
+impl<'r> UriBuilder for TaskUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/tasks", self.builder.build()?);
+        Ok(uri)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WithTaskUriBuilder<'r> {
+    builder: TaskUriBuilder<'r>,
+    task_id: u64,
+}
+
+impl<'r> WithTaskUriBuilder<'r> {
+    pub fn new(builder: TaskUriBuilder<'r>, task_id: u64) -> Self {
+        Self { builder, task_id }
+    }
+}
+
+impl<'r> UriBuilder for WithTaskUriBuilder<'r> {
+    fn build(&self) -> BuildResult {
+        let uri = format!("{}/{}", self.builder.build()?, self.task_id);
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri_builders::tests::{self, TEST_HOST};
+
+    fn builder<'a>() -> TaskUriBuilder<'a> {
+        ResourceUriBuilder::default().host(TEST_HOST).tasks()
+    }
+
+    #[test]
+    fn task_uri_works() {
+        let uri = builder().build();
+        assert_uri!(uri, format!("{}/tasks", tests::base_uri()));
+    }
+
+    #[test]
+    fn with_task_uri_works() {
+        let uri = builder().task(3).build();
+        assert_uri!(uri, format!("{}/tasks/3", tests::base_uri()));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::uri_builders::encode::encode_segment;
 use crate::uri_builders::{BuildResult, ResourceUriBuilder, TerminalUriBuilder, UriBuilder};
 
 #[derive(Debug, Clone)]
@@ -44,7 +45,7 @@ impl<'r> WithUserUriBuilder<'r> {
 
 impl<'r> UriBuilder for WithUserUriBuilder<'r> {
     fn build(&self) -> BuildResult {
-        let uri = format!("{}/{}", self.builder.build()?, self.user);
+        let uri = format!("{}/{}", self.builder.build()?, encode_segment(self.user)?);
         Ok(uri)
     }
 }
@@ -0,0 +1,90 @@
+use crate::common;
+use bitbucket_rs::models::get::BranchOrder;
+use bitbucket_rs::resources::BranchFilter;
+use httpmock::{
+    Method::{DELETE, GET, POST},
+    MockServer,
+};
+use serde_json::json;
+
+const BRANCH_UTILS_PATH: &str = "/rest/branch-utils/1.0/projects/my-project/repos/my-repo/branches";
+
+fn json_branch(name: &str) -> serde_json::Value {
+    json!({
+        "id": format!("refs/heads/{}", name),
+        "displayId": name,
+        "type": "BRANCH",
+        "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "latestChangeset": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "isDefault": false
+    })
+}
+
+#[test]
+fn get_branches_sends_filter() -> common::Result {
+    let ctx = blocking_context!(BranchResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/branches",
+            ))
+            .query_param("filterText", "release/")
+            .query_param("orderBy", "ALPHABETICAL");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 50,
+            "isLastPage": true,
+            "start": 0,
+            "values": [json_branch("release/1.0")]
+        }));
+    });
+
+    let filter = BranchFilter::default()
+        .filter_text("release/")
+        .order_by(BranchOrder::Alphabetical);
+    let branches = ctx.resource().get_branches(&filter)?;
+
+    mock.assert();
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].display_id, "release/1.0");
+
+    Ok(())
+}
+
+#[test]
+fn create_and_delete_branch_work() -> common::Result {
+    let ctx = blocking_context!(BranchResource, "my-project", "my-repo");
+
+    let create = ctx.server().mock(|when, then| {
+        when.method(POST).path(BRANCH_UTILS_PATH).json_body(json!({
+            "name": "release/1.0",
+            "startPoint": "refs/heads/master",
+            "message": "Branch for the 1.0 release"
+        }));
+        then.status(200).json_body(json_branch("release/1.0"));
+    });
+    let delete = ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path(BRANCH_UTILS_PATH)
+            .json_body(json!({
+                "name": "release/1.0",
+                "endPoint": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "dryRun": false
+            }));
+        then.status(204);
+    });
+
+    let branch = ctx.resource().create_branch(
+        "release/1.0",
+        "refs/heads/master",
+        Some("Branch for the 1.0 release"),
+    )?;
+    ctx.resource()
+        .delete_branch("release/1.0", Some(&branch.latest_commit))?;
+
+    create.assert();
+    delete.assert();
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use crate::common;
+use bitbucket_rs::resources::{CommitFilter, PageOptions};
+use httpmock::{
+    Method::{DELETE, GET, POST},
+    MockServer,
+};
+use serde_json::json;
+
+fn json_commit(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "displayId": &id[..7],
+        "author": { "name": "charlie", "emailAddress": "charlie@example.com" },
+        "authorTimestamp": 1548720847608u64,
+        "message": "More work on feature 1",
+        "parents": [{ "id": "abcdef0123abcdef4567abcdef8987abcdef6543", "displayId": "abcdef0" }]
+    })
+}
+
+fn json_page(ids: &[&str], start: u32, is_last_page: bool) -> serde_json::Value {
+    let values: Vec<_> = ids.iter().map(|id| json_commit(id)).collect();
+    let mut page = json!({
+        "size": values.len(),
+        "limit": values.len(),
+        "isLastPage": is_last_page,
+        "values": values,
+        "start": start,
+    });
+
+    if !is_last_page {
+        page["nextPageStart"] = json!(start as usize + ids.len());
+    }
+
+    page
+}
+
+#[test]
+fn get_commit_works() -> common::Result {
+    let ctx = blocking_context!(CommitResource, "my-project", "my-repo");
+    let id = "1111111111111111111111111111111111111111";
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(&format!(
+            "projects/my-project/repos/my-repo/commits/{}",
+            id
+        )));
+        then.status(200).json_body(json_commit(id));
+    });
+
+    let commit = ctx.resource().get_commit(id)?;
+    assert_eq!(commit.display_id, "1111111");
+
+    Ok(())
+}
+
+#[test]
+fn stream_commits_iterates_over_all_pages() -> common::Result {
+    let ctx = blocking_context!(CommitResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/commits");
+    let ids = [
+        "1111111111111111111111111111111111111111",
+        "2222222222222222222222222222222222222222",
+        "3333333333333333333333333333333333333333",
+        "4444444444444444444444444444444444444444",
+        "5555555555555555555555555555555555555555",
+    ];
+
+    let last_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("since", "v1.0")
+            .query_param("start", "4");
+        then.status(200).json_body(json_page(&ids[4..], 4, true));
+    });
+    let second_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("since", "v1.0")
+            .query_param("start", "2");
+        then.status(200).json_body(json_page(&ids[2..4], 2, false));
+    });
+    let first_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("since", "v1.0")
+            .query_param("limit", "2");
+        then.status(200).json_body(json_page(&ids[..2], 0, false));
+    });
+
+    let commits = ctx
+        .resource()
+        .stream_commits(
+            &CommitFilter::default().since("v1.0"),
+            PageOptions::default().page_size(2),
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    first_page.assert();
+    second_page.assert();
+    last_page.assert();
+    let display_ids: Vec<_> = commits
+        .iter()
+        .map(|commit| commit.display_id.as_str())
+        .collect();
+    assert_eq!(
+        display_ids,
+        vec!["1111111", "2222222", "3333333", "4444444", "5555555"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn watch_and_unwatch_commit_work() -> common::Result {
+    let ctx = blocking_context!(CommitResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/commits/abc123/watch");
+
+    let watch = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path);
+        then.status(204);
+    });
+    let unwatch = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(&path);
+        then.status(204);
+    });
+
+    ctx.resource().watch("abc123")?;
+    ctx.resource().unwatch("abc123")?;
+
+    watch.assert();
+    unwatch.assert();
+
+    Ok(())
+}
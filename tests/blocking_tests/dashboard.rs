@@ -0,0 +1,73 @@
+use crate::common;
+use bitbucket_rs::models::get::{PullRequestRole, PullRequestState};
+use bitbucket_rs::resources::DashboardPullRequestFilter;
+use httpmock::{Method::GET, MockServer};
+use serde_json::json;
+
+fn json_pull_request(id: u64) -> serde_json::Value {
+    let json_ref = |id: &str| {
+        json!({
+            "id": id,
+            "repository": { "slug": "my-repo", "project": { "key": "my-project" } }
+        })
+    };
+
+    json!({
+        "id": id,
+        "version": 0,
+        "title": "PR-title",
+        "state": "OPEN",
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "reviewers": [],
+        "participants": [],
+        "fromRef": json_ref("featureBranch"),
+        "toRef": json_ref("master"),
+        "links": { "self": [{ "href": "http://stash.test.com/pull-requests/1" }] }
+    })
+}
+
+#[test]
+fn get_dashboard_pull_requests_sends_filter() -> common::Result {
+    let ctx = blocking_context!(DashboardResource);
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("dashboard/pull-requests"))
+            .query_param("role", "REVIEWER")
+            .query_param("state", "OPEN");
+        then.status(200).json_body(json!({
+            "size": 1,
+            "limit": 50,
+            "isLastPage": true,
+            "start": 0,
+            "values": [json_pull_request(1)]
+        }));
+    });
+
+    let filter = DashboardPullRequestFilter::default()
+        .role(PullRequestRole::Reviewer)
+        .state(PullRequestState::Open);
+    let pull_requests = ctx.resource().get_pull_requests(&filter)?;
+
+    mock.assert();
+    assert_eq!(pull_requests.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn inbox_pull_request_count_works() -> common::Result {
+    let ctx = blocking_context!(DashboardResource);
+
+    ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("inbox/pull-requests/count"));
+        then.status(200).json_body(json!({ "count": 4 }));
+    });
+
+    let count = ctx.resource().get_inbox_pull_request_count()?;
+    assert_eq!(count.count, 4);
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+mod branch;
+mod commit;
+mod dashboard;
+mod project;
+mod pull_request;
+mod repository;
+mod tag;
@@ -0,0 +1,215 @@
+use crate::common;
+use bitbucket_rs::models::{get::BitbucketErrors, post};
+use bitbucket_rs::resources::PageOptions;
+use bitbucket_rs::Error;
+use httpmock::{
+    Method::{DELETE, GET, POST, PUT},
+    MockServer,
+};
+use serde_json::{json, Value};
+
+fn json_project(key: &str) -> Value {
+    json!({
+        "key": key,
+        "id": 1,
+        "name": "The test project",
+        "description": "The test project",
+        "public": true,
+        "type": "NORMAL",
+        "links": { "self": [ { "href": "http://stash.test.com/projects/test_project" } ] }
+    })
+}
+
+fn json_page(keys: &[&str], start: u32, is_last_page: bool) -> String {
+    let values: Vec<_> = keys.iter().map(|key| json_project(key)).collect();
+    let mut page = json!({
+        "size": values.len(),
+        "limit": values.len(),
+        "isLastPage": is_last_page,
+        "values": values,
+        "start": start,
+    });
+
+    if !is_last_page {
+        page["nextPageStart"] = json!(start as usize + keys.len());
+    }
+
+    page.to_string()
+}
+
+#[test]
+fn get_existing_project_works() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+    let json_project = json_project("test_project");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("projects/test_project"));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(json_project.to_string());
+    });
+
+    let project = ctx.resource().get_project("test_project")?;
+    assert_eq!(project, serde_json::from_value(json_project)?);
+
+    Ok(())
+}
+
+#[test]
+fn get_all_projects_works() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+    let path = common::format_path("projects");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(&path).query_param_exists("start");
+        then.status(200).body(json_page(&["Project2"], 1, true));
+    });
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(&path);
+        then.status(200).body(json_page(&["Project1"], 0, false));
+    });
+
+    let projects = ctx.resource().get_all_projects()?;
+    let keys: Vec<_> = projects.iter().map(|p| p.key.as_str()).collect();
+    assert_eq!(keys, vec!["Project1", "Project2"]);
+
+    Ok(())
+}
+
+#[test]
+fn create_project_works() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+
+    let project = post::Project {
+        key: "PTP".to_owned(),
+        name: "Post-Test-Project".to_owned(),
+        description: Some("This is a test project, please ignore".to_owned()),
+        avatar: None,
+    };
+
+    let json_project = json_project("PTP");
+    ctx.server().mock(|when, then| {
+        when.method(POST).path(common::format_path("projects"));
+        then.status(201).body(json_project.to_string());
+    });
+
+    let new_project = ctx.resource().create_project(&project)?;
+    assert_eq!(new_project, serde_json::from_value(json_project)?);
+
+    Ok(())
+}
+
+#[test]
+fn get_non_existent_project_returns_error() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+
+    let json_errors = json!({
+        "errors": [ { "message": "Project non_existent was not found" } ]
+    });
+
+    ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("projects/non_existent"));
+        then.status(404).body(json_errors.to_string());
+    });
+
+    let expected_errors: BitbucketErrors = serde_json::from_value(json_errors)?;
+    let error = ctx.resource().get_project("non_existent").unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.errors(), Some(&expected_errors));
+
+    Ok(())
+}
+
+#[test]
+fn update_project_works() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+    let path = common::format_path("projects/test");
+
+    let json_project = json_project("EPN");
+    ctx.server().mock(|when, then| {
+        when.method(PUT).path(&path);
+        then.status(200).body(json_project.to_string());
+    });
+
+    let payload = post::Project {
+        key: "EPN".to_owned(),
+        name: "The test project".to_owned(),
+        description: None,
+        avatar: None,
+    };
+
+    let updated_project = ctx.resource().update_project("test", &payload)?;
+    assert_eq!(updated_project, serde_json::from_value(json_project)?);
+
+    Ok(())
+}
+
+#[test]
+fn delete_project_works() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+
+    ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path(common::format_path("projects/test"));
+        then.status(204);
+    });
+
+    ctx.resource().delete_project("test")?;
+
+    Ok(())
+}
+
+#[test]
+fn failed_to_delete_returns_error() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+
+    ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path(common::format_path("projects/test"));
+        then.status(401)
+            .body(json!({ "errors": [ { "message": "You don't have permission" } ] }).to_string());
+    });
+
+    let error = ctx.resource().delete_project("test").unwrap_err();
+    assert!(matches!(error, Error::Auth { .. }));
+    assert!(error.is_unauthorized());
+
+    Ok(())
+}
+
+#[test]
+fn stream_all_projects_fetches_pages_lazily() -> common::Result {
+    let ctx = blocking_context!(ProjectResource);
+    let path = common::format_path("projects");
+
+    let second_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("limit", "2")
+            .query_param("start", "2");
+        then.status(200).body(json_page(&["P3"], 2, true));
+    });
+
+    let first_page = ctx.server().mock(|when, then| {
+        when.method(GET).path(&path).query_param("limit", "2");
+        then.status(200).body(json_page(&["P1", "P2"], 0, false));
+    });
+
+    let mut projects = ctx
+        .resource()
+        .stream_all_projects(PageOptions::default().page_size(2))?;
+
+    assert_eq!(projects.next().unwrap()?.key, "P1");
+    first_page.assert_hits(1);
+    second_page.assert_hits(0);
+
+    let rest = projects.collect::<Result<Vec<_>, _>>()?;
+    let keys: Vec<_> = rest.iter().map(|p| p.key.as_str()).collect();
+    assert_eq!(keys, vec!["P2", "P3"]);
+    second_page.assert_hits(1);
+
+    Ok(())
+}
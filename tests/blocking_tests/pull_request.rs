@@ -0,0 +1,257 @@
+use crate::common;
+use bitbucket_rs::models::get::{PullRequestState, TaskState};
+use bitbucket_rs::models::post;
+use httpmock::{
+    Method::{DELETE, POST, PUT},
+    MockServer,
+};
+use serde_json::json;
+
+fn post_ref(id: &str) -> post::PullRequestRef {
+    post::PullRequestRef {
+        id: id.to_owned(),
+        repository: post::PullRequestRefRepo {
+            slug: "my-repo".to_owned(),
+            name: None,
+            project: post::PullRequestRefRepoProject {
+                key: "my-project".to_owned(),
+            },
+        },
+    }
+}
+
+#[test]
+fn create_pull_request_works() -> common::Result {
+    let ctx = blocking_context!(PullRequestResource, "my-project", "my-repo");
+
+    let pull_request = post::PullRequest {
+        title: "PR-title".to_owned(),
+        description: Some("PR-description".to_owned()),
+        from_ref: post_ref("featureBranch"),
+        to_ref: post_ref("master"),
+        close_source_branch: false,
+        reviewers: Vec::new(),
+    };
+
+    let json_ref = |id: &str| {
+        json!({
+            "id": id,
+            "repository": { "slug": "my-repo", "project": { "key": "my-project" } }
+        })
+    };
+
+    let json_pull_request = json!({
+        "id": 1,
+        "version": 2,
+        "title": "PR-title",
+        "description": "PR-description",
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "reviewers": [],
+        "participants": [],
+        "fromRef": json_ref("featureBranch"),
+        "toRef": json_ref("master"),
+        "links": { "self": [ { "href": "http://stash.test.com/projects/my-project" } ] }
+    });
+
+    ctx.server().mock(|when, then| {
+        when.method(POST).path(common::format_path(
+            "projects/my-project/repos/my-repo/pull-requests",
+        ));
+        then.status(201).body(json_pull_request.to_string());
+    });
+
+    let new_pull_request = ctx.resource().create_pull_request(&pull_request)?;
+    assert_eq!(new_pull_request, serde_json::from_value(json_pull_request)?);
+
+    Ok(())
+}
+
+fn json_pull_request(version: u32, state: &str) -> serde_json::Value {
+    let json_ref = |id: &str| {
+        json!({
+            "id": id,
+            "repository": { "slug": "my-repo", "project": { "key": "my-project" } }
+        })
+    };
+
+    json!({
+        "id": 1,
+        "version": version,
+        "title": "PR-title",
+        "state": state,
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "reviewers": [],
+        "participants": [],
+        "fromRef": json_ref("featureBranch"),
+        "toRef": json_ref("master"),
+        "links": { "self": [{ "href": "http://stash.test.com/pull-requests/1" }] }
+    })
+}
+
+fn json_user() -> serde_json::Value {
+    json!({
+        "name": "charlie",
+        "emailAddress": "charlie@example.com",
+        "id": 7,
+        "displayName": "Charlie",
+        "active": true,
+        "slug": "charlie",
+        "type": "NORMAL"
+    })
+}
+
+#[test]
+fn decline_reopen_and_merge_pull_request_work() -> common::Result {
+    let ctx = blocking_context!(PullRequestResource, "my-project", "my-repo");
+    let path = |action: &str| {
+        common::format_path(&format!(
+            "projects/my-project/repos/my-repo/pull-requests/1/{}",
+            action
+        ))
+    };
+
+    let decline = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(path("decline"))
+            .query_param("version", "0");
+        then.status(200).json_body(json_pull_request(1, "DECLINED"));
+    });
+    let reopen = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(path("reopen"))
+            .query_param("version", "1");
+        then.status(200).json_body(json_pull_request(2, "OPEN"));
+    });
+    let merge = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(path("merge"))
+            .query_param("version", "2");
+        then.status(200).json_body(json_pull_request(3, "MERGED"));
+    });
+
+    let pull_request = ctx.resource().decline_pull_request(1, 0)?;
+    assert_eq!(pull_request.state, Some(PullRequestState::Declined));
+    let pull_request = ctx
+        .resource()
+        .reopen_pull_request(1, pull_request.version)?;
+    assert_eq!(pull_request.state, Some(PullRequestState::Open));
+    let pull_request = ctx.resource().merge_pull_request(1, pull_request.version)?;
+    assert_eq!(pull_request.state, Some(PullRequestState::Merged));
+
+    decline.assert();
+    reopen.assert();
+    merge.assert();
+
+    Ok(())
+}
+
+#[test]
+fn create_update_and_delete_comment_work() -> common::Result {
+    let ctx = blocking_context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/comments");
+    let comment_path = format!("{}/5", path);
+
+    let json_comment = |version: u32, text: &str| {
+        json!({
+            "id": 5,
+            "version": version,
+            "text": text,
+            "author": json_user(),
+            "createdDate": 1649346771,
+            "updatedDate": 1649346776,
+            "comments": []
+        })
+    };
+
+    let create = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(&path)
+            .json_body(json!({ "text": "Unused import" }));
+        then.status(201).json_body(json_comment(0, "Unused import"));
+    });
+    let update = ctx.server().mock(|when, then| {
+        when.method(PUT).path(&comment_path).json_body(json!({
+            "version": 0,
+            "text": "Unused import (fixed)"
+        }));
+        then.status(200)
+            .json_body(json_comment(1, "Unused import (fixed)"));
+    });
+    let delete = ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path(&comment_path)
+            .query_param("version", "1");
+        then.status(204);
+    });
+
+    let comment = post::Comment {
+        text: "Unused import".to_owned(),
+        parent: None,
+        anchor: None,
+    };
+    let comment = ctx.resource().create_comment(1, &comment)?;
+    let comment =
+        ctx.resource()
+            .update_comment(1, comment.id, comment.version, "Unused import (fixed)")?;
+    ctx.resource()
+        .delete_comment(1, comment.id, comment.version)?;
+
+    create.assert();
+    update.assert();
+    delete.assert();
+
+    Ok(())
+}
+
+#[test]
+fn create_resolve_and_reopen_task_work() -> common::Result {
+    let ctx = blocking_context!(PullRequestResource, "my-project", "my-repo");
+
+    let json_task = |state: &str| {
+        json!({
+            "id": 9,
+            "createdDate": 1649346771,
+            "author": json_user(),
+            "text": "Add a test",
+            "state": state,
+            "anchor": { "id": 5, "type": "COMMENT" }
+        })
+    };
+
+    let create = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(common::format_path("tasks"))
+            .json_body(json!({
+                "anchor": { "id": 5, "type": "COMMENT" },
+                "text": "Add a test"
+            }));
+        then.status(201).json_body(json_task("OPEN"));
+    });
+    let resolve = ctx.server().mock(|when, then| {
+        when.method(PUT)
+            .path(common::format_path("tasks/9"))
+            .json_body(json!({ "state": "RESOLVED" }));
+        then.status(200).json_body(json_task("RESOLVED"));
+    });
+    let reopen = ctx.server().mock(|when, then| {
+        when.method(PUT)
+            .path(common::format_path("tasks/9"))
+            .json_body(json!({ "state": "OPEN" }));
+        then.status(200).json_body(json_task("OPEN"));
+    });
+
+    let task = ctx.resource().create_task(5, "Add a test")?;
+    assert_eq!(task.state, TaskState::Open);
+    let task = ctx.resource().resolve_task(task.id)?;
+    assert_eq!(task.state, TaskState::Resolved);
+    let task = ctx.resource().reopen_task(task.id)?;
+    assert_eq!(task.state, TaskState::Open);
+
+    create.assert();
+    resolve.assert();
+    reopen.assert();
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::common;
+use httpmock::{Method::GET, MockServer};
+use serde_json::{json, Value};
+
+fn json_repo() -> Value {
+    json!({
+        "slug": "my-repo",
+        "id": 1,
+        "name": "My repo",
+        "scmId": "git",
+        "state": "AVAILABLE",
+        "statusMessage": "Available",
+        "forkable": true,
+        "project": {
+            "key": "PRJ",
+            "id": 1,
+            "name": "My Cool Project",
+            "public": true,
+            "type": "NORMAL",
+            "links": { "self": [ { "href": "http://link/to/project" } ] }
+        },
+        "public": true,
+        "links": {
+            "clone": [ { "href": "https://<baseURL>/scm/PRJ/my-repo.git", "name": "http" } ],
+            "self": [ { "href": "http://link/to/repository" } ]
+        }
+    })
+}
+
+#[test]
+fn get_all_repositories_works() -> common::Result {
+    let ctx = blocking_context!(RepositoryResource, "PRJ");
+
+    let json_page = json!({
+        "size": 1,
+        "limit": 25,
+        "isLastPage": true,
+        "values": [ json_repo() ],
+        "start": 0
+    });
+
+    ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("projects/PRJ/repos"));
+        then.status(200).body(json_page.to_string());
+    });
+
+    let repos = ctx.resource().get_all_repositories()?;
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0], serde_json::from_value(json_repo())?);
+
+    Ok(())
+}
+
+#[test]
+fn get_existing_repo_works() -> common::Result {
+    let ctx = blocking_context!(RepositoryResource, "PRJ");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("projects/PRJ/repos/test"));
+        then.status(200).body(json_repo().to_string());
+    });
+
+    let repo = ctx.resource().get_repository("test")?;
+    assert_eq!(repo, serde_json::from_value(json_repo())?);
+
+    Ok(())
+}
@@ -0,0 +1,479 @@
+use crate::common;
+use bitbucket_rs::client::{
+    BitbucketClient, BitbucketClientBuilder, RateLimit, RequestLimiter, RetryPolicy,
+};
+use bitbucket_rs::models::get::{BitbucketErrors, Project};
+use bitbucket_rs::resources::ProjectResource;
+use bitbucket_rs::traits::AsyncRestClient;
+use bitbucket_rs::Error;
+use httpmock::{
+    Method::{GET, POST, PUT},
+    MockServer,
+};
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn empty_response_body_is_allowed_for_unit() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(PUT).path("/watch");
+        then.status(204);
+    });
+
+    let res: () = client.put(&server.url("/watch"), None::<()>).await?;
+    assert_eq!(res, ());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_response_body_is_an_error_for_models() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(POST).path("/projects");
+        then.status(204);
+    });
+
+    let res = client
+        .post::<Project, _>(&server.url("/projects"), None::<()>)
+        .await;
+    assert!(matches!(res, Err(Error::Deserialize { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_json_error_page_keeps_status() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects");
+        then.status(502)
+            .header(common::CONTENT_TYPE, "text/html")
+            .body("<html><body>Bad Gateway</body></html>");
+    });
+
+    let error = client
+        .get_as::<Project>(&server.url("/projects"))
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::Http { status, errors } => {
+            assert_eq!(status, StatusCode::BAD_GATEWAY);
+            assert_eq!(errors, BitbucketErrors::default());
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_json_success_response_is_an_error() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects");
+        then.status(200)
+            .header(common::CONTENT_TYPE, "text/html; charset=UTF-8")
+            .body("<html><body>Login</body></html>");
+    });
+
+    let error = client
+        .get_as::<Project>(&server.url("/projects"))
+        .await
+        .unwrap_err();
+
+    match error {
+        Error::ContentType {
+            status,
+            content_type,
+            body,
+        } => {
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(content_type, "text/html; charset=UTF-8");
+            assert_eq!(body, "<html><body>Login</body></html>");
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_status_is_reported_before_decoding() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects");
+        then.status(500)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(r#"{ "errors": [ { "message": "Internal error" } ] }"#);
+    });
+
+    let error = client
+        .get_as::<Project>(&server.url("/projects"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    assert_eq!(error.errors().unwrap().errors[0].message, "Internal error");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn requests_are_sent_below_the_context_path() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = BitbucketClient::from_url(&format!("{}/bitbucket/", server.base_url()))?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/bitbucket/rest/api/1.0/projects/PRJ");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(json_project());
+    });
+
+    ProjectResource::new(&client).get_project("PRJ").await?;
+    mock.assert();
+
+    Ok(())
+}
+
+#[test]
+fn client_rejects_urls_with_query() {
+    let error = BitbucketClient::from_url("https://git.corp.example/bitbucket?x=1").unwrap_err();
+    assert!(matches!(error, Error::UriBuild(_)));
+}
+
+fn make_retrying_client(server: &MockServer, policy: RetryPolicy) -> BitbucketClient {
+    BitbucketClientBuilder::default()
+        .base_url(server.base_url().parse().unwrap())
+        .retry_policy(policy)
+        .build()
+        .unwrap()
+}
+
+fn fast_retries(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::default()
+        .max_attempts(max_attempts)
+        .base_delay(Duration::from_millis(1))
+        .jitter(false)
+}
+
+fn json_project() -> &'static str {
+    r#"
+    {
+        "key": "PRJ",
+        "id": 1,
+        "name": "Project",
+        "public": true,
+        "type": "NORMAL",
+        "links": { "self": [ { "href": "http://stash.test.com/projects/PRJ" } ] }
+    }"#
+}
+
+// The matcher functions of httpmock cannot capture state, so every test that needs
+// a server failing a number of times before succeeding owns a static counter.
+macro_rules! failing_mock {
+    ($server:expr, $method:expr, $path:expr, $failures:expr, $status:expr) => {{
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+        $server.mock(|when, then| {
+            when.method($method)
+                .path($path)
+                .matches(|_| REQUESTS.fetch_add(1, Ordering::SeqCst) < $failures);
+            then.status($status);
+        })
+    }};
+}
+
+#[tokio::test]
+async fn retries_until_success() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(3));
+
+    let failing = failing_mock!(server, GET, "/projects/PRJ", 2, 503);
+    let succeeding = server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let project: Project = client.get_as(&server.url("/projects/PRJ")).await?;
+    assert_eq!(project.key, "PRJ");
+    failing.assert_hits(2);
+    succeeding.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gives_up_after_max_attempts() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(2));
+
+    let failing = failing_mock!(server, GET, "/projects/PRJ", 5, 502);
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let error = client
+        .get_as::<Project>(&server.url("/projects/PRJ"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.status(), Some(StatusCode::BAD_GATEWAY));
+    failing.assert_hits(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_retry_non_idempotent_requests_by_default() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(3));
+
+    let failing = failing_mock!(server, POST, "/projects", 1, 503);
+    server.mock(|when, then| {
+        when.method(POST).path("/projects");
+        then.status(201).body(json_project());
+    });
+
+    let res = client
+        .post::<Project, _>(&server.url("/projects"), None::<()>)
+        .await;
+    assert_eq!(
+        res.unwrap_err().status(),
+        Some(StatusCode::SERVICE_UNAVAILABLE)
+    );
+    failing.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_non_idempotent_requests_when_allowed() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(3).idempotent_only(false));
+
+    let failing = failing_mock!(server, POST, "/projects", 1, 503);
+    server.mock(|when, then| {
+        when.method(POST).path("/projects");
+        then.status(201).body(json_project());
+    });
+
+    let project = client
+        .post::<Project, _>(&server.url("/projects"), None::<()>)
+        .await?;
+    assert_eq!(project.key, "PRJ");
+    failing.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_retry_other_statuses() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(3));
+
+    let failing = failing_mock!(server, GET, "/projects/PRJ", 1, 404);
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let error = client
+        .get_as::<Project>(&server.url("/projects/PRJ"))
+        .await
+        .unwrap_err();
+    assert!(error.is_not_found());
+    failing.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn honours_retry_after() -> common::Result {
+    static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+    let server = MockServer::start_async().await;
+    let client = make_retrying_client(&server, fast_retries(2));
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/projects/PRJ")
+            .matches(|_| REQUESTS.fetch_add(1, Ordering::SeqCst) < 1);
+        then.status(429).header("Retry-After", "1");
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let started = Instant::now();
+    let project: Project = client.get_as(&server.url("/projects/PRJ")).await?;
+    assert_eq!(project.key, "PRJ");
+    assert!(started.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn limits_requests_in_flight_across_clones() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = BitbucketClientBuilder::default()
+        .base_url(server.base_url().parse()?)
+        .limiter(RequestLimiter::new(None, Some(1)))
+        .build()?;
+    let clone = client.clone();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200)
+            .delay(Duration::from_millis(100))
+            .body(json_project());
+    });
+
+    let uri = server.url("/projects/PRJ");
+    let started = Instant::now();
+    let (first, second) = futures::join!(
+        client.get_as::<Project>(&uri),
+        clone.get_as::<Project>(&uri)
+    );
+    first?;
+    second?;
+
+    assert!(started.elapsed() >= Duration::from_millis(200));
+
+    let metrics = clone.limiter().metrics();
+    assert_eq!(metrics.requests, 2);
+    assert_eq!(metrics.delayed_requests, 1);
+    assert!(metrics.concurrency_wait >= Duration::from_millis(90));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn holds_the_in_flight_permit_until_the_body_is_read() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = BitbucketClientBuilder::default()
+        .base_url(server.base_url().parse()?)
+        .limiter(RequestLimiter::new(None, Some(1)))
+        .build()?;
+
+    let large_body = "x".repeat(16 * 1024 * 1024);
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ/avatar.png");
+        then.status(200).body(&large_body);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let download = async {
+        let resp = client.get(&server.url("/projects/PRJ/avatar.png")).await?;
+        let body = resp.bytes().await?;
+        Ok::<_, anyhow::Error>((Instant::now(), body.len()))
+    };
+    let project = async {
+        client
+            .get_as::<Project>(&server.url("/projects/PRJ"))
+            .await?;
+        Ok::<_, anyhow::Error>(Instant::now())
+    };
+    let (download, project) = futures::join!(download, project);
+    let (downloaded_at, len) = download?;
+    let project_at = project?;
+
+    assert_eq!(len, large_body.len());
+    assert!(project_at > downloaded_at);
+    assert_eq!(client.limiter().metrics().delayed_requests, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn releases_the_in_flight_permit_when_the_response_is_dropped() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = BitbucketClientBuilder::default()
+        .base_url(server.base_url().parse()?)
+        .limiter(RequestLimiter::new(None, Some(1)))
+        .build()?;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let resp = client.get(&server.url("/projects/PRJ")).await?;
+    drop(resp);
+
+    let project = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.get_as::<Project>(&server.url("/projects/PRJ")),
+    )
+    .await??;
+
+    assert_eq!(project.key, "PRJ");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_keeps_the_request_url() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = common::make_client(&server);
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let url = server.url("/projects/PRJ");
+    let resp = client.get(&url).await?;
+
+    assert_eq!(resp.url().as_str(), url);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limits_requests() -> common::Result {
+    let server = MockServer::start_async().await;
+    let client = BitbucketClientBuilder::default()
+        .base_url(server.base_url().parse()?)
+        .limiter(RequestLimiter::new(Some(RateLimit::new(10.0, 1)), None))
+        .build()?;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/projects/PRJ");
+        then.status(200).body(json_project());
+    });
+
+    let uri = server.url("/projects/PRJ");
+    let started = Instant::now();
+    for _ in 0..3 {
+        client.get_as::<Project>(&uri).await?;
+    }
+
+    assert!(started.elapsed() >= Duration::from_millis(190));
+
+    let metrics = client.limiter().metrics();
+    assert_eq!(metrics.requests, 3);
+    assert!(metrics.rate_limit_wait >= Duration::from_millis(100));
+    assert_eq!(metrics.concurrency_wait, Duration::ZERO);
+
+    Ok(())
+}
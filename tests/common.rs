@@ -1,9 +1,13 @@
-#![allow(dead_code)]
+#![allow(
+    dead_code,
+    clippy::crate_in_macro_def,
+    clippy::redundant_static_lifetimes
+)]
 
+use bitbucket_rs::auth::Authorization;
 use bitbucket_rs::client::BitbucketClient;
 use bitbucket_rs::Scheme;
 use httpmock::MockServer;
-use bitbucket_rs::auth::Authorization;
 
 pub type Result = anyhow::Result<()>;
 
@@ -12,21 +16,32 @@ pub const CONTENT_TYPE_JSON: &'static str = "application/json; charset=UTF-8";
 pub const REST_PATH_PREFIX: &'static str = "/rest/api/1.0";
 
 pub fn make_client(server: &MockServer) -> BitbucketClient {
-    BitbucketClient::with_auth(&server.address().to_string(), Scheme::HTTP, Authorization::Bear("token".to_owned()))
+    BitbucketClient::with_auth(
+        &server.address().to_string(),
+        Scheme::HTTP,
+        Authorization::Bear("token".to_owned()),
+    )
+}
+
+#[cfg(feature = "blocking")]
+pub fn make_blocking_client(
+    server: &MockServer,
+) -> bitbucket_rs::blocking::BlockingBitbucketClient {
+    bitbucket_rs::blocking::BlockingBitbucketClient::from_client(make_client(server))
 }
 
 pub fn format_path(path: &str) -> String {
     format!("{}/{}", REST_PATH_PREFIX, path)
 }
 
-pub struct TestContext<'c, R> {
-    server: &'c MockServer,
-    client: &'c BitbucketClient,
+pub struct TestContext<'c, R, C = BitbucketClient> {
+    server: MockServer,
+    client: &'c C,
     resource: R,
 }
 
-impl<'c, R> TestContext<'c, R> {
-    pub fn new(server: &'c MockServer, client: &'c BitbucketClient, resource: R) -> Self {
+impl<'c, R, C> TestContext<'c, R, C> {
+    pub fn new(server: MockServer, client: &'c C, resource: R) -> Self {
         Self {
             server,
             client,
@@ -35,10 +50,10 @@ impl<'c, R> TestContext<'c, R> {
     }
 
     pub fn server(&self) -> &MockServer {
-        self.server
+        &self.server
     }
 
-    pub fn client(&self) -> &BitbucketClient {
+    pub fn client(&self) -> &C {
         self.client
     }
 
@@ -52,8 +67,9 @@ impl<'c, R> TestContext<'c, R> {
 mod tests {
     macro_rules! __context {
         ($server:ident, $client:ident) => {
-            let $server = std::boxed::Box::new(MockServer::start_async().await);
-            let $server = std::boxed::Box::leak($server);
+            // The server is owned by the context so that it is handed back to the pool of
+            // httpmock once the test is done, only the client is leaked.
+            let $server = MockServer::start_async().await;
             let $client = std::boxed::Box::new(crate::common::make_client(&$server));
             let $client = std::boxed::Box::leak($client);
         };
@@ -79,4 +95,27 @@ mod tests {
             crate::common::TestContext::new(server, client, resource)
         }};
     }
+
+    macro_rules! __blocking_context {
+        ($server:ident, $client:ident) => {
+            let $server = MockServer::start();
+            let $client = std::boxed::Box::new(crate::common::make_blocking_client(&$server));
+            let $client = std::boxed::Box::leak($client);
+        };
+    }
+
+    #[macro_export]
+    macro_rules! blocking_context {
+        ($resource_type:tt) => {{
+            __blocking_context!(server, client);
+            let resource = bitbucket_rs::blocking::resources::$resource_type::new(client);
+            crate::common::TestContext::new(server, client, resource)
+        }};
+
+        ($resource_type:tt, $($args:expr),*) => {{
+            __blocking_context!(server, client);
+            let resource = bitbucket_rs::blocking::resources::$resource_type::new(client, $($args),*);
+            crate::common::TestContext::new(server, client, resource)
+        }};
+    }
 }
@@ -3,4 +3,7 @@ extern crate serde_json;
 
 #[macro_use]
 mod common;
+#[cfg(feature = "blocking")]
+mod blocking_tests;
+mod client_tests;
 mod resource_tests;
@@ -0,0 +1,130 @@
+use crate::common;
+use bitbucket_rs::models::get::BranchOrder;
+use bitbucket_rs::resources::BranchFilter;
+use httpmock::{
+    Method::{DELETE, GET, POST, PUT},
+    MockServer,
+};
+use serde_json::json;
+
+const BRANCH_UTILS_PATH: &str = "/rest/branch-utils/1.0/projects/my-project/repos/my-repo/branches";
+
+fn json_branch(name: &str) -> serde_json::Value {
+    json!({
+        "id": format!("refs/heads/{}", name),
+        "displayId": name,
+        "type": "BRANCH",
+        "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "latestChangeset": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "isDefault": false
+    })
+}
+
+#[tokio::test]
+async fn get_branches_with_details_works() -> common::Result {
+    let ctx = context!(BranchResource, "my-project", "my-repo");
+
+    let mut branch = json_branch("release/1.0");
+    branch["metadata"] = json!({
+        "com.atlassian.bitbucket.server.bitbucket-branch:ahead-behind-metadata-provider": {
+            "ahead": 2,
+            "behind": 5
+        },
+        "com.atlassian.bitbucket.server.bitbucket-jira:branch-list-jira-issues": []
+    });
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/branches",
+            ))
+            .query_param("filterText", "release/")
+            .query_param("orderBy", "ALPHABETICAL")
+            .query_param("base", "refs/heads/master")
+            .query_param("details", "true");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [branch]
+            }));
+    });
+
+    let filter = BranchFilter::default()
+        .filter_text("release/")
+        .order_by(BranchOrder::Alphabetical)
+        .base("refs/heads/master")
+        .details(true);
+    let branches = ctx.resource().get_branches(&filter).await?;
+
+    mock.assert();
+    assert_eq!(branches.len(), 1);
+    let metadata = branches[0].metadata.as_ref().unwrap();
+    let ahead_behind = metadata.ahead_behind.as_ref().unwrap();
+    assert_eq!((ahead_behind.ahead, ahead_behind.behind), (2, 5));
+    assert_eq!(metadata.other.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_and_delete_branch_use_branch_utils() -> common::Result {
+    let ctx = context!(BranchResource, "my-project", "my-repo");
+
+    let create = ctx.server().mock(|when, then| {
+        when.method(POST).path(BRANCH_UTILS_PATH).json_body(json!({
+            "name": "release/1.0",
+            "startPoint": "refs/heads/master"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_branch("release/1.0"));
+    });
+    let delete = ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path(BRANCH_UTILS_PATH)
+            .json_body(json!({
+                "name": "release/1.0",
+                "endPoint": "8d51122def5632836d1cb1026e879069e10a1e13",
+                "dryRun": false
+            }));
+        then.status(204);
+    });
+
+    let branch = ctx
+        .resource()
+        .create_branch("release/1.0", "refs/heads/master", None)
+        .await?;
+    ctx.resource()
+        .delete_branch("release/1.0", Some(&branch.latest_commit))
+        .await?;
+
+    create.assert();
+    delete.assert();
+    assert_eq!(branch.display_id, "release/1.0");
+    assert!(branch.metadata.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_default_branch_works() -> common::Result {
+    let ctx = context!(BranchResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(PUT)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/branches/default",
+            ))
+            .json_body(json!({ "id": "refs/heads/main" }));
+        then.status(204);
+    });
+
+    ctx.resource().set_default_branch("refs/heads/main").await?;
+
+    mock.assert();
+
+    Ok(())
+}
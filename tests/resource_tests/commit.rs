@@ -0,0 +1,293 @@
+use crate::common;
+use bitbucket_rs::models::get::{ChangeType, CommitMerges, DiffSegmentType};
+use bitbucket_rs::resources::{ChangesOptions, CommitFilter, DiffOptions, PageOptions};
+use bitbucket_rs::Error;
+use futures::TryStreamExt;
+use httpmock::{
+    Method::{DELETE, GET, POST},
+    MockServer,
+};
+use serde_json::json;
+
+fn json_commit(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "displayId": &id[..7],
+        "author": { "name": "charlie", "emailAddress": "charlie@example.com" },
+        "authorTimestamp": 1548720847608u64,
+        "message": "More work on feature 1",
+        "parents": [{ "id": "abcdef0123abcdef4567abcdef8987abcdef6543", "displayId": "abcdef0" }]
+    })
+}
+
+#[tokio::test]
+async fn stream_commits_between_tags_works() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/commits");
+
+    let second_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("since", "v1.0")
+            .query_param("start", "1");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 1,
+                "isLastPage": true,
+                "start": 1,
+                "values": [json_commit("2222222222222222222222222222222222222222")]
+            }));
+    });
+    let first_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("since", "v1.0")
+            .query_param("until", "v1.1")
+            .query_param("path", "src/lib.rs")
+            .query_param("merges", "exclude")
+            .query_param("followRenames", "true")
+            .query_param("ignoreMissing", "true")
+            .query_param("withCounts", "false")
+            .query_param("limit", "1");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 1,
+                "isLastPage": false,
+                "nextPageStart": 1,
+                "start": 0,
+                "values": [json_commit("1111111111111111111111111111111111111111")]
+            }));
+    });
+
+    let filter = CommitFilter::default()
+        .since("v1.0")
+        .until("v1.1")
+        .path("src/lib.rs")
+        .merges(CommitMerges::Exclude)
+        .follow_renames(true)
+        .ignore_missing(true)
+        .with_counts(false);
+    let commits: Vec<_> = ctx
+        .resource()
+        .stream_commits(&filter, PageOptions::default().page_size(1))?
+        .try_collect()
+        .await?;
+
+    first_page.assert();
+    second_page.assert();
+    let ids: Vec<_> = commits
+        .iter()
+        .map(|commit| commit.display_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["1111111", "2222222"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_and_unwatch_commit_work() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/commits/abc123/watch");
+
+    let watch = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path);
+        then.status(204);
+    });
+    let unwatch = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(&path);
+        then.status(204);
+    });
+
+    ctx.resource().watch("abc123").await?;
+    ctx.resource().unwatch("abc123").await?;
+
+    watch.assert();
+    unwatch.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_commit_without_access_fails() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(POST).path(common::format_path(
+            "projects/my-project/repos/my-repo/commits/abc123/watch",
+        ));
+        then.status(403)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "errors": [{ "message": "You are not permitted to access this resource" }]
+            }));
+    });
+
+    let err = ctx.resource().watch("abc123").await.unwrap_err();
+
+    mock.assert();
+    assert!(matches!(err, Error::Auth { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_commit_exposes_committer_and_properties() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+
+    let mut commit = json_commit("1111111111111111111111111111111111111111");
+    commit["committer"] = json!({ "name": "dave", "emailAddress": "dave@example.com" });
+    commit["committerTimestamp"] = json!(1548720847610u64);
+    commit["properties"] = json!({ "jira-key": ["PRJ-42"] });
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(
+            "projects/my-project/repos/my-repo/commits/1111111111111111111111111111111111111111",
+        ));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(commit);
+    });
+
+    let commit = ctx
+        .resource()
+        .get_commit("1111111111111111111111111111111111111111")
+        .await?;
+
+    mock.assert();
+    assert_eq!(commit.committer.unwrap().name, "dave");
+    assert_eq!(commit.committer_timestamp, Some(1548720847610));
+    assert_eq!(commit.properties["jira-key"], json!(["PRJ-42"]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_commit_changes_since_works() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/commits/abc123/changes",
+            ))
+            .query_param("since", "def456");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [{
+                    "contentId": "abc",
+                    "fromContentId": "def",
+                    "path": {
+                        "components": ["README.md"],
+                        "parent": "",
+                        "name": "README.md",
+                        "extension": "md",
+                        "toString": "README.md"
+                    },
+                    "type": "MODIFY",
+                    "nodeType": "FILE",
+                    "executable": false,
+                    "percentUnchanged": -1
+                }]
+            }));
+    });
+
+    let changes = ctx
+        .resource()
+        .get_commit_changes("abc123", Some("def456"), &ChangesOptions::default())
+        .await?;
+
+    mock.assert();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].r#type, ChangeType::Modify);
+    assert_eq!(changes[0].path.path, "README.md");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_commit_diff_for_path_works() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/commits/abc123/diff/src/lib.rs",
+            ))
+            .query_param("contextLines", "0");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "fromHash": "def456",
+                "toHash": "abc123",
+                "contextLines": 0,
+                "whitespace": "SHOW",
+                "diffs": [{
+                    "source": {
+                        "components": ["src", "lib.rs"],
+                        "parent": "src",
+                        "name": "lib.rs",
+                        "extension": "rs",
+                        "toString": "src/lib.rs"
+                    },
+                    "destination": null,
+                    "hunks": [{
+                        "sourceLine": 1,
+                        "sourceSpan": 1,
+                        "destinationLine": 0,
+                        "destinationSpan": 0,
+                        "segments": [{
+                            "type": "REMOVED",
+                            "lines": [{ "source": 1, "destination": 0, "line": "pub mod old;" }]
+                        }]
+                    }]
+                }]
+            }));
+    });
+
+    let options = DiffOptions::default().context_lines(0).path("src/lib.rs");
+    let diff = ctx
+        .resource()
+        .get_commit_diff("abc123", None, &options)
+        .await?;
+
+    mock.assert();
+    assert_eq!(diff.from_hash.as_deref(), Some("def456"));
+    let file = &diff.diffs[0];
+    assert!(file.destination.is_none());
+    assert_eq!(file.hunks[0].segments[0].r#type, DiffSegmentType::Removed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_commit_diff_trims_slashes_from_path() -> common::Result {
+    let ctx = context!(CommitResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(
+            "projects/my-project/repos/my-repo/commits/abc123/diff/src/lib.rs",
+        ));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({ "fromHash": "def456", "toHash": "abc123", "diffs": [] }));
+    });
+
+    let options = DiffOptions::default().path("/src/lib.rs");
+    let diff = ctx
+        .resource()
+        .get_commit_diff("abc123", None, &options)
+        .await?;
+
+    mock.assert();
+    assert!(diff.diffs.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use crate::common;
+use bitbucket_rs::models::get::{
+    PullRequestOrder, PullRequestParticipantStatus, PullRequestRole, PullRequestState,
+};
+use bitbucket_rs::resources::{DashboardPullRequestFilter, PageOptions};
+use futures::TryStreamExt;
+use httpmock::{Method::GET, MockServer};
+use serde_json::json;
+use std::time::Duration;
+
+fn json_pull_request(id: u64, repository: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "version": 0,
+        "title": "PR-title",
+        "state": "MERGED",
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "reviewers": [],
+        "participants": [],
+        "fromRef": {
+            "id": "featureBranch",
+            "repository": { "slug": repository, "project": { "key": "my-project" } }
+        },
+        "toRef": {
+            "id": "master",
+            "repository": { "slug": repository, "project": { "key": "my-project" } }
+        },
+        "links": { "self": [{ "href": "http://stash.test.com/pull-requests/1" }] }
+    })
+}
+
+#[tokio::test]
+async fn stream_dashboard_pull_requests_sends_filter() -> common::Result {
+    let ctx = context!(DashboardResource);
+    let path = common::format_path("dashboard/pull-requests");
+
+    let second = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("role", "REVIEWER")
+            .query_param("start", "1");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 1,
+                "isLastPage": true,
+                "start": 1,
+                "values": [json_pull_request(2, "repo-b")]
+            }));
+    });
+
+    let first = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("role", "REVIEWER")
+            .query_param("state", "MERGED")
+            .query_param("participantStatus", "APPROVED")
+            .query_param("closedSince", "604800")
+            .query_param("order", "NEWEST")
+            .query_param("limit", "1");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 1,
+                "isLastPage": false,
+                "nextPageStart": 1,
+                "start": 0,
+                "values": [json_pull_request(1, "repo-a")]
+            }));
+    });
+
+    let filter = DashboardPullRequestFilter::default()
+        .role(PullRequestRole::Reviewer)
+        .state(PullRequestState::Merged)
+        .participant_status(PullRequestParticipantStatus::Approved)
+        .closed_since(Duration::from_secs(7 * 24 * 60 * 60))
+        .order(PullRequestOrder::Newest);
+    let pull_requests: Vec<_> = ctx
+        .resource()
+        .stream_pull_requests(&filter, PageOptions::default().page_size(1))?
+        .try_collect()
+        .await?;
+
+    first.assert();
+    second.assert();
+    let repositories: Vec<_> = pull_requests
+        .iter()
+        .map(|pull_request| pull_request.to_ref.repository.slug.as_str())
+        .collect();
+    assert_eq!(repositories, vec!["repo-a", "repo-b"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inbox_pull_requests_and_count_work() -> common::Result {
+    let ctx = context!(DashboardResource);
+
+    let list = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("inbox/pull-requests"))
+            .query_param("role", "AUTHOR");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [json_pull_request(3, "repo-c")]
+            }));
+    });
+    let count = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("inbox/pull-requests/count"));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({ "count": 4 }));
+    });
+
+    let pull_requests = ctx
+        .resource()
+        .get_inbox_pull_requests(Some(PullRequestRole::Author))
+        .await?;
+    let pull_request_count = ctx.resource().get_inbox_pull_request_count().await?;
+
+    list.assert();
+    count.assert();
+    assert_eq!(pull_requests.len(), 1);
+    assert_eq!(pull_request_count.count, 4);
+
+    Ok(())
+}
@@ -1,3 +1,8 @@
+mod branch;
+mod commit;
+mod dashboard;
+#[allow(clippy::needless_borrow)]
 mod project;
 mod pull_request;
 mod repository;
+mod tag;
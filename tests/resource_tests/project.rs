@@ -1,5 +1,8 @@
 use crate::common;
 use bitbucket_rs::models::{get::BitbucketErrors, post};
+use bitbucket_rs::resources::PageOptions;
+use bitbucket_rs::Error;
+use futures::TryStreamExt;
 use httpmock::{
     Method::{DELETE, GET, POST, PUT},
     MockServer,
@@ -198,13 +201,10 @@ async fn get_non_existent_project_returns_error() -> common::Result {
     let resp = ctx.resource().get_project("non_existent").await;
     assert!(resp.is_err());
 
-    let errors = resp
-        .as_ref()
-        .unwrap_err()
-        .downcast_ref::<BitbucketErrors>()
-        .unwrap();
-
-    assert_eq!(expected_errors, *errors);
+    let error = resp.unwrap_err();
+    assert!(matches!(error, Error::Http { .. }));
+    assert!(error.is_not_found());
+    assert_eq!(error.errors(), Some(&expected_errors));
 
     Ok(())
 }
@@ -249,7 +249,7 @@ async fn update_project_works() -> common::Result {
         then.status(200).body(updated_json_project);
     });
 
-    let existing_project = ctx.resource().get_project(project).await?;
+    let existing_project = ctx.resource().get_project(&project).await?;
     assert_eq!(existing_project, existing_expected_project);
 
     let new_project = post::Project {
@@ -303,17 +303,128 @@ async fn failed_to_delete_returns_error() -> common::Result {
         then.status(401).body(errors);
     });
 
-    let expected_errors = serde_json::from_str(errors)?;
+    let expected_errors: BitbucketErrors = serde_json::from_str(errors)?;
     let resp = ctx.resource().delete_project("test").await;
     assert!(resp.is_err());
 
-    let errors = resp
-        .as_ref()
-        .unwrap_err()
-        .downcast_ref::<BitbucketErrors>()
-        .unwrap();
+    let error = resp.unwrap_err();
+    assert!(matches!(error, Error::Auth { .. }));
+    assert!(error.is_unauthorized());
+    assert_eq!(error.errors(), Some(&expected_errors));
+
+    Ok(())
+}
+
+fn json_project_page(keys: &[&str], start: u32, is_last_page: bool) -> String {
+    let values: Vec<_> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            json!({
+                "key": key,
+                "id": start as usize + i,
+                "name": key,
+                "public": true,
+                "type": "NORMAL",
+                "links": { "self": [ { "href": "http://stash.test.com/projects" } ] }
+            })
+        })
+        .collect();
+
+    let mut page = json!({
+        "size": values.len(),
+        "limit": values.len(),
+        "isLastPage": is_last_page,
+        "values": values,
+        "start": start,
+    });
+
+    if !is_last_page {
+        page["nextPageStart"] = json!(start as usize + keys.len());
+    }
+
+    page.to_string()
+}
+
+#[tokio::test]
+async fn stream_all_projects_fetches_pages_lazily() -> common::Result {
+    let ctx = context!(ProjectResource);
+    let path = common::format_path("projects");
+
+    let second_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("limit", "2")
+            .query_param("start", "2");
+        then.status(200)
+            .body(json_project_page(&["P3", "P4"], 2, true));
+    });
+
+    let first_page = ctx.server().mock(|when, then| {
+        when.method(GET).path(&path).query_param("limit", "2");
+        then.status(200)
+            .body(json_project_page(&["P1", "P2"], 0, false));
+    });
+
+    let mut projects = ctx
+        .resource()
+        .stream_all_projects(PageOptions::default().page_size(2))?;
+
+    let first = projects.try_next().await?.unwrap();
+    assert_eq!(first.key, "P1");
+    first_page.assert_hits(1);
+    second_page.assert_hits(0);
+
+    let rest: Vec<_> = projects.try_collect().await?;
+    let keys: Vec<_> = rest.iter().map(|p| p.key.as_str()).collect();
+    assert_eq!(keys, vec!["P2", "P3", "P4"]);
+    second_page.assert_hits(1);
 
-    assert_eq!(*errors, expected_errors);
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_all_projects_respects_start_and_max_items() -> common::Result {
+    let ctx = context!(ProjectResource);
+    let path = common::format_path("projects");
+
+    let page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("limit", "3")
+            .query_param("start", "5");
+        then.status(200)
+            .body(json_project_page(&["P6", "P7", "P8"], 5, false));
+    });
+
+    let projects: Vec<_> = ctx
+        .resource()
+        .stream_all_projects(PageOptions::default().page_size(10).start(5).max_items(3))?
+        .try_collect()
+        .await?;
+
+    let keys: Vec<_> = projects.iter().map(|p| p.key.as_str()).collect();
+    assert_eq!(keys, vec!["P6", "P7", "P8"]);
+    page.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_response_returns_deserialize_error() -> common::Result {
+    let ctx = context!(ProjectResource);
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path("projects/test"));
+        then.status(200).body(r#"{ "key": "test" }"#);
+    });
+
+    let error = ctx.resource().get_project("test").await.unwrap_err();
+
+    match error {
+        Error::Deserialize { body, .. } => assert_eq!(body, r#"{ "key": "test" }"#),
+        e => panic!("unexpected error {:?}", e),
+    }
 
     Ok(())
 }
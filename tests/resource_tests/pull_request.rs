@@ -1,6 +1,17 @@
 use crate::common;
+use bitbucket_rs::models::get::{
+    ChangeType, CommentFileType, CommentLineType, DiffSegmentType, PullRequestActivityAction,
+    PullRequestActivityFromType, PullRequestDirection, PullRequestOrder,
+    PullRequestParticipantStatus, PullRequestRole, PullRequestState, TaskState,
+};
 use bitbucket_rs::models::post;
-use httpmock::{Method::POST, MockServer};
+use bitbucket_rs::resources::{ChangesOptions, DiffOptions, PageOptions, PullRequestFilter};
+use futures::TryStreamExt;
+use httpmock::{
+    Method::{DELETE, GET, POST, PUT},
+    MockServer,
+};
+use serde_json::json;
 
 #[tokio::test]
 async fn create_pull_request_works() -> common::Result {
@@ -84,3 +95,893 @@ async fn create_pull_request_works() -> common::Result {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn stream_pull_requests_sends_state_as_query_param() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("state", "MERGED")
+            .query_param("limit", "10");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(r#"{"size": 0, "limit": 10, "isLastPage": true, "values": [], "start": 0}"#);
+    });
+
+    let pull_requests: Vec<_> = ctx
+        .resource()
+        .stream_all_merged_pull_requests(PageOptions::default().page_size(10))?
+        .try_collect()
+        .await?;
+
+    mock.assert();
+    assert!(pull_requests.is_empty());
+
+    Ok(())
+}
+
+fn json_pull_request(version: u32, state: &str) -> String {
+    json!({
+        "id": 1,
+        "version": version,
+        "title": "PR-title",
+        "state": state,
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "reviewers": [],
+        "participants": [],
+        "fromRef": {
+            "id": "featureBranch",
+            "repository": { "slug": "my-repo", "project": { "key": "my-project" } }
+        },
+        "toRef": {
+            "id": "master",
+            "repository": { "slug": "my-repo", "project": { "key": "my-project" } }
+        },
+        "links": { "self": [{ "href": "http://stash.test.com/pull-requests/1" }] }
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn update_pull_request_sends_only_changed_fields() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(PUT).path(&path).json_body(json!({
+            "version": 2,
+            "title": "New title",
+            "toRef": {
+                "id": "release/1.0",
+                "repository": { "slug": "my-repo", "name": null, "project": { "key": "my-project" } }
+            }
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(json_pull_request(3, "OPEN"));
+    });
+
+    let update = post::PullRequestUpdate {
+        title: Some("New title".to_owned()),
+        to_ref: Some(post::PullRequestRef {
+            id: "release/1.0".to_owned(),
+            repository: post::PullRequestRefRepo {
+                slug: "my-repo".to_owned(),
+                name: None,
+                project: post::PullRequestRefRepoProject {
+                    key: "my-project".to_owned(),
+                },
+            },
+        }),
+        ..Default::default()
+    };
+    let pull_request = ctx.resource().update_pull_request(1, 2, &update).await?;
+
+    mock.assert();
+    assert_eq!(pull_request.version, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn merge_pull_request_sends_version() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/merge");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).query_param("version", "2");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(json_pull_request(3, "MERGED"));
+    });
+
+    let pull_request = ctx.resource().merge_pull_request(1, 2).await?;
+
+    mock.assert();
+    assert_eq!(pull_request.version, 3);
+    assert_eq!(pull_request.state, Some(PullRequestState::Merged));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn decline_pull_request_with_stale_version_returns_current_version() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/decline");
+
+    ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).query_param("version", "1");
+        then.status(409)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "errors": [{
+                    "context": null,
+                    "message": "You are attempting to modify a pull request based on out-of-date information.",
+                    "exceptionName": "com.atlassian.bitbucket.pull.PullRequestOutOfDateException",
+                    "currentVersion": 4,
+                    "expectedVersion": 1
+                }]
+            }));
+    });
+
+    let error = ctx.resource().decline_pull_request(1, 1).await.unwrap_err();
+
+    assert!(error.is_stale_version());
+    assert_eq!(error.current_version(), Some(4));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_merge_returns_vetoes() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/merge");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(&path);
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "canMerge": false,
+                "conflicted": true,
+                "outcome": "CONFLICTED",
+                "vetoes": [{
+                    "summaryMessage": "Not enough approvals",
+                    "detailedMessage": "At least 2 approvals are required"
+                }]
+            }));
+    });
+
+    let status = ctx.resource().can_merge(1).await?;
+
+    assert!(!status.can_merge);
+    assert!(status.conflicted);
+    assert_eq!(status.vetoes.len(), 1);
+    assert_eq!(status.vetoes[0].summary_message, "Not enough approvals");
+
+    Ok(())
+}
+
+fn json_participant(role: &str, status: &str) -> serde_json::Value {
+    json!({
+        "user": {
+            "name": "charlie",
+            "emailAddress": "charlie@example.com",
+            "id": 7,
+            "displayName": "Charlie",
+            "active": true,
+            "slug": "charlie",
+            "type": "NORMAL"
+        },
+        "role": role,
+        "approved": status == "APPROVED",
+        "status": status
+    })
+}
+
+#[tokio::test]
+async fn set_reviewer_status_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path(
+        "projects/my-project/repos/my-repo/pull-requests/1/participants/john_acme.com",
+    );
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(PUT).path(&path).json_body(json!({
+            "user": { "name": "john@acme.com" },
+            "status": "NEEDS_WORK"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_participant("REVIEWER", "NEEDS_WORK"));
+    });
+
+    let participant = ctx
+        .resource()
+        .set_reviewer_status(
+            1,
+            "john_acme.com",
+            "john@acme.com",
+            PullRequestParticipantStatus::NeedsWork,
+        )
+        .await?;
+
+    mock.assert();
+    assert_eq!(participant.role, PullRequestRole::Reviewer);
+    assert_eq!(participant.status, PullRequestParticipantStatus::NeedsWork);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_all_participants_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path =
+        common::format_path("projects/my-project/repos/my-repo/pull-requests/1/participants");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(&path);
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 2,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [
+                    json_participant("AUTHOR", "UNAPPROVED"),
+                    json_participant("REVIEWER", "APPROVED")
+                ]
+            }));
+    });
+
+    let participants = ctx.resource().get_all_participants(1).await?;
+
+    assert_eq!(participants.len(), 2);
+    assert_eq!(participants[0].role, PullRequestRole::Author);
+    assert!(participants[1].approved);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_reviewer_and_remove_participant_work() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path =
+        common::format_path("projects/my-project/repos/my-repo/pull-requests/1/participants");
+
+    let add = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(json!({
+            "user": { "name": "charlie" },
+            "role": "REVIEWER"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_participant("REVIEWER", "UNAPPROVED"));
+    });
+    let remove = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(format!("{}/charlie", path));
+        then.status(204);
+    });
+
+    ctx.resource().add_reviewer(1, "charlie").await?;
+    ctx.resource().remove_participant(1, "charlie").await?;
+
+    add.assert();
+    remove.assert();
+
+    Ok(())
+}
+
+fn json_comment(id: u64, version: u32, text: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "version": version,
+        "text": text,
+        "author": json_participant("REVIEWER", "UNAPPROVED")["user"],
+        "createdDate": 1649346771,
+        "updatedDate": 1649346776,
+        "comments": [],
+        "properties": { "repositoryId": 84 },
+        "anchor": {
+            "path": "src/lib.rs",
+            "line": 12,
+            "lineType": "ADDED",
+            "fileType": "TO"
+        }
+    })
+}
+
+#[tokio::test]
+async fn create_inline_comment_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/comments");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(json!({
+            "text": "Unused import",
+            "anchor": {
+                "path": "src/lib.rs",
+                "line": 12,
+                "lineType": "ADDED",
+                "fileType": "TO"
+            }
+        }));
+        then.status(201)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_comment(5, 0, "Unused import"));
+    });
+
+    let comment = post::Comment {
+        text: "Unused import".to_owned(),
+        parent: None,
+        anchor: Some(post::CommentAnchor {
+            path: "src/lib.rs".to_owned(),
+            src_path: None,
+            line: Some(12),
+            line_type: Some(CommentLineType::Added),
+            file_type: Some(CommentFileType::To),
+        }),
+    };
+    let comment = ctx.resource().create_comment(1, &comment).await?;
+
+    mock.assert();
+    let anchor = comment.anchor.unwrap();
+    assert_eq!(anchor.line_type, Some(CommentLineType::Added));
+    assert_eq!(anchor.file_type, Some(CommentFileType::To));
+    assert_eq!(comment.properties["repositoryId"], 84);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reply_to_comment_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/comments");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(json!({
+            "text": "Fixed",
+            "parent": { "id": 5 }
+        }));
+        then.status(201)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_comment(6, 0, "Fixed"));
+    });
+
+    ctx.resource().reply_to_comment(1, 5, "Fixed").await?;
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_and_delete_comment_send_version() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/comments/5");
+
+    let update = ctx.server().mock(|when, then| {
+        when.method(PUT).path(&path).json_body(json!({
+            "version": 0,
+            "text": "Unused import (fixed)"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_comment(5, 1, "Unused import (fixed)"));
+    });
+    let delete = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(&path).query_param("version", "1");
+        then.status(204);
+    });
+
+    let comment = ctx
+        .resource()
+        .update_comment(1, 5, 0, "Unused import (fixed)")
+        .await?;
+    ctx.resource().delete_comment(1, 5, comment.version).await?;
+
+    update.assert();
+    delete.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_activities_from_deserializes_typed_actions() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/activities");
+    let user = json_participant("REVIEWER", "UNAPPROVED")["user"].clone();
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("fromId", "5")
+            .query_param("fromType", "COMMENT");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 3,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [
+                    {
+                        "id": 3,
+                        "createdDate": 1649346790,
+                        "user": user,
+                        "action": "COMMENTED",
+                        "commentAction": "ADDED",
+                        "comment": json_comment(5, 0, "Unused import"),
+                        "commentAnchor": { "path": "src/lib.rs", "line": 12, "lineType": "ADDED", "fileType": "TO" }
+                    },
+                    {
+                        "id": 2,
+                        "createdDate": 1649346780,
+                        "user": user,
+                        "action": "RESCOPED",
+                        "fromHash": "abc",
+                        "previousFromHash": "def",
+                        "added": { "commits": [], "total": 0 },
+                        "removed": { "commits": [], "total": 0 }
+                    },
+                    {
+                        "id": 1,
+                        "createdDate": 1649346770,
+                        "user": user,
+                        "action": "AUTO_MERGE_REQUESTED"
+                    }
+                ]
+            }));
+    });
+
+    let activities: Vec<_> = ctx
+        .resource()
+        .stream_all_activities_from(
+            1,
+            5,
+            PullRequestActivityFromType::Comment,
+            PageOptions::default(),
+        )?
+        .try_collect()
+        .await?;
+
+    mock.assert();
+    assert_eq!(activities.len(), 3);
+    match &activities[0].action {
+        PullRequestActivityAction::Commented {
+            comment, anchor, ..
+        } => {
+            assert_eq!(comment.id, 5);
+            assert_eq!(anchor.as_ref().unwrap().line, Some(12));
+        }
+        action => panic!("unexpected action {:?}", action),
+    }
+    assert!(matches!(
+        &activities[1].action,
+        PullRequestActivityAction::Rescoped { from_hash: Some(hash), .. } if hash == "abc"
+    ));
+    match &activities[2].action {
+        PullRequestActivityAction::Unknown(raw) => {
+            assert_eq!(raw["action"], "AUTO_MERGE_REQUESTED")
+        }
+        action => panic!("unexpected action {:?}", action),
+    }
+
+    Ok(())
+}
+
+fn json_task(state: &str) -> serde_json::Value {
+    json!({
+        "id": 9,
+        "createdDate": 1649346771,
+        "author": json_participant("REVIEWER", "UNAPPROVED")["user"],
+        "text": "Add a test",
+        "state": state,
+        "anchor": { "id": 5, "type": "COMMENT" }
+    })
+}
+
+#[tokio::test]
+async fn get_task_count_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/tasks/count");
+
+    ctx.server().mock(|when, then| {
+        when.method(GET).path(&path);
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({ "open": 2, "resolved": 1 }));
+    });
+
+    let count = ctx.resource().get_task_count(1).await?;
+    assert_eq!(count.open, 2);
+    assert_eq!(count.resolved, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_and_resolve_task_work() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let create = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(common::format_path("tasks"))
+            .json_body(json!({
+                "anchor": { "id": 5, "type": "COMMENT" },
+                "text": "Add a test"
+            }));
+        then.status(201)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_task("OPEN"));
+    });
+    let resolve = ctx.server().mock(|when, then| {
+        when.method(PUT)
+            .path(common::format_path("tasks/9"))
+            .json_body(json!({ "state": "RESOLVED" }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_task("RESOLVED"));
+    });
+
+    let task = ctx.resource().create_task(5, "Add a test").await?;
+    assert_eq!(task.state, TaskState::Open);
+    let task = ctx.resource().resolve_task(task.id).await?;
+    assert_eq!(task.state, TaskState::Resolved);
+
+    create.assert();
+    resolve.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pull_request_changes_sends_changes_options() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/pull-requests/1/changes",
+            ))
+            .query_param("withComments", "false")
+            .query_param("limit", "50");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [{
+                    "contentId": "abc",
+                    "path": {
+                        "components": ["src", "new.rs"],
+                        "parent": "src",
+                        "name": "new.rs",
+                        "extension": "rs",
+                        "toString": "src/new.rs"
+                    },
+                    "srcPath": {
+                        "components": ["src", "old.rs"],
+                        "parent": "src",
+                        "name": "old.rs",
+                        "extension": "rs",
+                        "toString": "src/old.rs"
+                    },
+                    "type": "MOVE",
+                    "nodeType": "FILE"
+                }]
+            }));
+    });
+
+    let options = ChangesOptions::default().with_comments(false);
+    let changes = ctx.resource().get_pull_request_changes(1, &options).await?;
+
+    mock.assert();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].r#type, ChangeType::Move);
+    assert_eq!(changes[0].path.path, "src/new.rs");
+    assert_eq!(changes[0].src_path.as_ref().unwrap().path, "src/old.rs");
+
+    Ok(())
+}
+
+fn json_change(path: &str, src_path: Option<&str>, change_type: &str) -> serde_json::Value {
+    let json_path = |path: &str| {
+        json!({
+            "components": path.split('/').collect::<Vec<_>>(),
+            "parent": path.rsplit_once('/').map_or("", |(parent, _)| parent),
+            "name": path.rsplit('/').next(),
+            "toString": path
+        })
+    };
+
+    json!({
+        "path": json_path(path),
+        "srcPath": src_path.map(json_path),
+        "type": change_type,
+        "nodeType": "FILE"
+    })
+}
+
+#[tokio::test]
+async fn stream_pull_request_changes_filters_by_path() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let second_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/pull-requests/1/changes",
+            ))
+            .query_param("start", "3");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 2,
+                "limit": 3,
+                "isLastPage": true,
+                "start": 3,
+                "values": [
+                    json_change("src/lib.rs", None, "MODIFY"),
+                    json_change("src-gen/api.rs", None, "ADD")
+                ]
+            }));
+    });
+    let first_page = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/pull-requests/1/changes",
+            ))
+            .query_param("limit", "3");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 3,
+                "limit": 3,
+                "isLastPage": false,
+                "start": 0,
+                "nextPageStart": 3,
+                "values": [
+                    json_change("README.md", None, "MODIFY"),
+                    json_change("docs/new.md", Some("src/old.md"), "MOVE"),
+                    json_change("docs/guide.md", None, "DELETE")
+                ]
+            }));
+    });
+
+    let options = ChangesOptions::default().path("/src/");
+    let changes: Vec<_> = ctx
+        .resource()
+        .stream_pull_request_changes(1, &options, PageOptions::default().page_size(3))?
+        .try_collect()
+        .await?;
+
+    first_page.assert();
+    second_page.assert();
+    let paths: Vec<_> = changes
+        .iter()
+        .map(|change| change.path.path.as_str())
+        .collect();
+    assert_eq!(paths, ["docs/new.md", "src/lib.rs"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_change_type_is_kept() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(
+            "projects/my-project/repos/my-repo/pull-requests/1/changes",
+        ));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 1,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [{
+                    "contentId": "abc",
+                    "path": {
+                        "components": ["README.md"],
+                        "parent": "",
+                        "name": "README.md",
+                        "extension": "md",
+                        "toString": "README.md"
+                    },
+                    "type": "UNKNOWN",
+                    "nodeType": "FILE"
+                }]
+            }));
+    });
+
+    let changes = ctx
+        .resource()
+        .get_pull_request_changes(1, &ChangesOptions::default())
+        .await?;
+
+    mock.assert();
+    assert_eq!(changes[0].r#type, ChangeType::Unknown("UNKNOWN".to_owned()));
+    assert_eq!(changes[0].r#type.as_str(), "UNKNOWN");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pull_request_diff_for_path_works() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/pull-requests/1/diff/src/lib.rs",
+            ))
+            .query_param("contextLines", "3")
+            .query_param("whitespace", "ignore-all");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "fromHash": "aaa",
+                "toHash": "bbb",
+                "contextLines": 3,
+                "whitespace": "IGNORE_ALL",
+                "diffs": [{
+                    "source": null,
+                    "destination": {
+                        "components": ["src", "lib.rs"],
+                        "parent": "src",
+                        "name": "lib.rs",
+                        "extension": "rs",
+                        "toString": "src/lib.rs"
+                    },
+                    "hunks": [{
+                        "sourceLine": 0,
+                        "sourceSpan": 0,
+                        "destinationLine": 1,
+                        "destinationSpan": 1,
+                        "segments": [{
+                            "type": "ADDED",
+                            "lines": [{
+                                "source": 0,
+                                "destination": 1,
+                                "line": "pub mod models;",
+                                "truncated": false
+                            }],
+                            "truncated": false
+                        }],
+                        "truncated": false
+                    }],
+                    "truncated": false
+                }],
+                "truncated": false
+            }));
+    });
+
+    let options = DiffOptions::default()
+        .context_lines(3)
+        .ignore_whitespace(true)
+        .path("src/lib.rs");
+    let diff = ctx.resource().get_pull_request_diff(1, &options).await?;
+
+    mock.assert();
+    assert_eq!(diff.diffs.len(), 1);
+    let file = &diff.diffs[0];
+    assert!(file.source.is_none());
+    assert_eq!(file.hunks[0].segments[0].r#type, DiffSegmentType::Added);
+    assert_eq!(file.hunks[0].segments[0].lines[0].line, "pub mod models;");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_and_unwatch_pull_request_work() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/pull-requests/1/watch");
+
+    let watch = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path);
+        then.status(204);
+    });
+    let unwatch = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(&path);
+        then.status(204);
+    });
+
+    ctx.resource().watch(1).await?;
+    ctx.resource().unwatch(1).await?;
+
+    watch.assert();
+    unwatch.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pull_requests_sends_filter() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/pull-requests",
+            ))
+            .query_param("state", "OPEN")
+            .query_param("direction", "INCOMING")
+            .query_param("at", "refs/heads/release")
+            .query_param("order", "OLDEST")
+            .query_param("withAttributes", "false")
+            .query_param("withProperties", "false");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .body(format!(
+                r#"{{"size": 1, "limit": 50, "isLastPage": true, "values": [{}], "start": 0}}"#,
+                json_pull_request(1, "OPEN")
+            ));
+    });
+
+    let filter = PullRequestFilter::default()
+        .state(PullRequestState::Open)
+        .direction(PullRequestDirection::Incoming)
+        .at("refs/heads/release")
+        .order(PullRequestOrder::Oldest)
+        .with_attributes(false)
+        .with_properties(false);
+    let pull_requests = ctx.resource().get_pull_requests(&filter).await?;
+
+    mock.assert();
+    assert_eq!(pull_requests.len(), 1);
+    assert_eq!(pull_requests[0].state, Some(PullRequestState::Open));
+
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_state_names_still_match() {
+    let state = PullRequestState::from("MERGED".to_owned());
+
+    assert!(matches!(state, PullRequestState::MERGED));
+    assert_eq!(PullRequestState::OPEN, PullRequestState::Open);
+}
+
+#[tokio::test]
+async fn unknown_states_and_roles_are_kept() -> common::Result {
+    let ctx = context!(PullRequestResource, "my-project", "my-repo");
+
+    let mut pull_request: serde_json::Value = serde_json::from_str(&json_pull_request(1, "DRAFT"))?;
+    pull_request["reviewers"] = json!([json_participant("OBSERVER", "SKIPPED")]);
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(
+            "projects/my-project/repos/my-repo/pull-requests/1",
+        ));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(pull_request);
+    });
+
+    let pull_request = ctx.resource().get_pull_request(1).await?;
+
+    mock.assert();
+    let state = pull_request.state.unwrap();
+    assert_eq!(state, PullRequestState::Unknown("DRAFT".to_owned()));
+    assert_eq!(state.as_str(), "DRAFT");
+    let reviewer = &pull_request.reviewers[0];
+    assert_eq!(
+        reviewer.role,
+        PullRequestRole::Unknown("OBSERVER".to_owned())
+    );
+    assert_eq!(
+        reviewer.status,
+        PullRequestParticipantStatus::Unknown("SKIPPED".to_owned())
+    );
+
+    Ok(())
+}
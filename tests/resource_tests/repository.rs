@@ -114,3 +114,116 @@ async fn get_existing_repo_works() -> common::Result {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn create_repository_works() -> common::Result {
+    let ctx = context!(RepositoryResource, "PRJ");
+    let path = common::format_path("projects/PRJ/repos");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(serde_json::json!({
+            "name": "My repo",
+            "scmId": "git",
+            "forkable": true
+        }));
+        then.status(201).body(get_repo());
+    });
+
+    let repository = post::Repository {
+        name: Some("My repo".to_owned()),
+        scm_id: Some("git".to_owned()),
+        forkable: Some(true),
+        ..Default::default()
+    };
+    let repo = ctx.resource().create_repository(&repository).await?;
+
+    mock.assert();
+    assert_eq!(repo.slug, "my-repo");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_repository_moves_it_to_another_project() -> common::Result {
+    let ctx = context!(RepositoryResource, "PRJ");
+    let path = common::format_path("projects/PRJ/repos/my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(PUT).path(&path).json_body(serde_json::json!({
+            "name": "Renamed repo",
+            "project": { "key": "OTHER" }
+        }));
+        then.status(200).body(get_repo());
+    });
+
+    let payload = post::Repository {
+        name: Some("Renamed repo".to_owned()),
+        project: Some(post::RepositoryProject {
+            key: "OTHER".to_owned(),
+        }),
+        ..Default::default()
+    };
+    ctx.resource()
+        .update_repository("my-repo", &payload)
+        .await?;
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fork_and_delete_repository_work() -> common::Result {
+    let ctx = context!(RepositoryResource, "PRJ");
+    let path = common::format_path("projects/PRJ/repos/my-repo");
+
+    let fork = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(serde_json::json!({
+            "name": "my-fork",
+            "project": { "key": "~ALICE" }
+        }));
+        then.status(201).body(get_repo());
+    });
+    let delete = ctx.server().mock(|when, then| {
+        when.method(DELETE).path(&path);
+        then.status(202);
+    });
+
+    ctx.resource()
+        .fork_repository("my-repo", "~ALICE", Some("my-fork"))
+        .await?;
+    ctx.resource().delete_repository("my-repo").await?;
+
+    fork.assert();
+    delete.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recreate_repository_and_get_forks_work() -> common::Result {
+    let ctx = context!(RepositoryResource, "PRJ");
+
+    let recreate = ctx.server().mock(|when, then| {
+        when.method(POST)
+            .path(common::format_path("projects/PRJ/repos/my-repo/recreate"));
+        then.status(201).body(get_repo());
+    });
+    let forks = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path("projects/PRJ/repos/my-repo/forks"));
+        then.status(200).body(format!(
+            r#"{{"size": 1, "limit": 25, "isLastPage": true, "values": [{}], "start": 0}}"#,
+            get_repo()
+        ));
+    });
+
+    ctx.resource().recreate_repository("my-repo").await?;
+    let repos = ctx.resource().get_repository_forks("my-repo").await?;
+
+    recreate.assert();
+    forks.assert();
+    assert_eq!(repos.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::common;
+use bitbucket_rs::models::get::TagOrder;
+use bitbucket_rs::resources::TagFilter;
+use httpmock::{
+    Method::{DELETE, GET, POST},
+    MockServer,
+};
+use serde_json::json;
+
+fn json_tag(name: &str) -> serde_json::Value {
+    json!({
+        "id": format!("refs/tags/{}", name),
+        "displayId": name,
+        "type": "TAG",
+        "latestCommit": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "latestChangeset": "8d51122def5632836d1cb1026e879069e10a1e13",
+        "hash": "8d51122def5632836d1cb1026e879069e10a1e13"
+    })
+}
+
+#[tokio::test]
+async fn get_tags_sends_filter() -> common::Result {
+    let ctx = context!(TagResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET)
+            .path(common::format_path(
+                "projects/my-project/repos/my-repo/tags",
+            ))
+            .query_param("filterText", "v1.")
+            .query_param("orderBy", "MODIFICATION");
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json!({
+                "size": 2,
+                "limit": 50,
+                "isLastPage": true,
+                "start": 0,
+                "values": [json_tag("v1.1"), json_tag("v1.0")]
+            }));
+    });
+
+    let filter = TagFilter::default()
+        .filter_text("v1.")
+        .order_by(TagOrder::Modification);
+    let tags = ctx.resource().get_tags(&filter).await?;
+
+    mock.assert();
+    let names: Vec<_> = tags.iter().map(|tag| tag.display_id.as_str()).collect();
+    assert_eq!(names, vec!["v1.1", "v1.0"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_tag_by_name_works() -> common::Result {
+    let ctx = context!(TagResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(GET).path(common::format_path(
+            "projects/my-project/repos/my-repo/tags/release/1.0",
+        ));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_tag("release/1.0"));
+    });
+
+    let tag = ctx.resource().get_tag("release/1.0").await?;
+
+    mock.assert();
+    assert_eq!(tag.id, "refs/tags/release/1.0");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_lightweight_and_annotated_tags_work() -> common::Result {
+    let ctx = context!(TagResource, "my-project", "my-repo");
+    let path = common::format_path("projects/my-project/repos/my-repo/tags");
+
+    let lightweight = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(json!({
+            "name": "build-42",
+            "startPoint": "8d51122def5632836d1cb1026e879069e10a1e13"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_tag("build-42"));
+    });
+    let annotated = ctx.server().mock(|when, then| {
+        when.method(POST).path(&path).json_body(json!({
+            "name": "v1.0",
+            "startPoint": "8d51122def5632836d1cb1026e879069e10a1e13",
+            "message": "Release 1.0"
+        }));
+        then.status(200)
+            .header(common::CONTENT_TYPE, common::CONTENT_TYPE_JSON)
+            .json_body(json_tag("v1.0"));
+    });
+
+    let commit = "8d51122def5632836d1cb1026e879069e10a1e13";
+    ctx.resource().create_tag("build-42", commit).await?;
+    ctx.resource()
+        .create_annotated_tag("v1.0", commit, "Release 1.0")
+        .await?;
+
+    lightweight.assert();
+    annotated.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_tag_uses_git_api() -> common::Result {
+    let ctx = context!(TagResource, "my-project", "my-repo");
+
+    let mock = ctx.server().mock(|when, then| {
+        when.method(DELETE)
+            .path("/rest/git/1.0/projects/my-project/repos/my-repo/tags/v1.0");
+        then.status(204);
+    });
+
+    ctx.resource().delete_tag("v1.0").await?;
+
+    mock.assert();
+
+    Ok(())
+}